@@ -1,4 +1,5 @@
 use ::bevy::prelude::*;
+use ::my_lib::phase_graph::PhaseGraph;
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, States)]
 pub enum GamePhase {
@@ -8,3 +9,15 @@ pub enum GamePhase {
   Loading,
   MainMenu,
 }
+
+impl GamePhase {
+  pub fn graph() -> PhaseGraph<Self> {
+    PhaseGraph::default()
+      .allow(Self::Loading, Self::MainMenu)
+      .allow(Self::MainMenu, Self::Flapping)
+      .allow(Self::Flapping, Self::GameOver)
+      .allow(Self::Flapping, Self::MainMenu)
+      .allow(Self::GameOver, Self::MainMenu)
+      .allow(Self::GameOver, Self::Flapping)
+  }
+}
@@ -1,30 +1,44 @@
 use self::dragon::Dragon;
-use self::dragon_element::DragonElement;
 use self::game_phase::GamePhase;
 use self::obstacle::Obstacle;
+use self::score::Score;
 use ::bevy::prelude::*;
-use ::bevy::window::WindowResolution;
-use ::my_lib::add_phase;
+use ::my_lib::app_phase_ext::AppPhaseExt;
 use ::my_lib::bevy_assets::asset_manager::AssetManager;
 use ::my_lib::bevy_assets::asset_store::LoadedAssets;
 use ::my_lib::bevy_assets::asset_store::{AssetResource, AssetStore};
+use ::my_lib::game_result::GameResult;
 use ::my_lib::game_state_plugin::GameStatePlugin;
+use ::my_lib::high_scores::high_score_plugin::HighScorePlugin;
+use ::my_lib::menu_controls::MenuControls;
+use ::my_lib::phase_graph::PhaseGraph;
 use ::my_lib::random::RandomNumberGenerator;
 use ::my_lib::random_plugin::RandomPlugin;
+use ::my_lib::settings::game_settings::GameSettings;
+use ::my_lib::settings::settings_plugin::SettingsPlugin;
 use ::my_lib::spawn_image;
+use ::my_lib::transitions::transition::Transition;
+use ::my_lib::transitions::transition_plugin::TransitionPlugin;
 
 mod dragon;
-mod dragon_element;
 mod game_phase;
 mod obstacle;
+mod score;
 
 fn main() -> ::anyhow::Result<()> {
-  let resolution: WindowResolution = WindowResolution::new(1024, 768);
+  let phase_graph: PhaseGraph<GamePhase> = GamePhase::graph();
+
+  if phase_graph.print_requested_diagram() {
+    return Ok(());
+  }
+
+  let settings_plugin: SettingsPlugin =
+    SettingsPlugin::load("dragon").bind("flap", KeyCode::Space);
 
   let primary_window: Option<Window> = Some(Window {
     // position: WindowPosition::Centered(MonitorSelection::Primary),
     position: WindowPosition::At(IVec2::new(300, 0)),
-    resolution,
+    resolution: settings_plugin.settings.window_resolution(),
     title: "Flappy Dragon - Bevy Edition".to_string(),
     ..default()
   });
@@ -36,33 +50,44 @@ fn main() -> ::anyhow::Result<()> {
 
   let mut app: App = App::new();
 
-  add_phase!(
-    app,
-    GamePhase,
-    GamePhase::Flapping,
-    start => [ setup ],
-    run => [ gravity, flap, clamp, move_walls, hit_wall ],
-    exit => [ ::my_lib::cleanup::<DragonElement> ]
-  );
+  add_flapping_phase(&mut app);
 
   let asset_manager: AssetManager = AssetManager::default()
     .add_image("dragon", "dragon-52x45.png")?
     .add_image("wall", "wall-32x32.png")?;
 
+  let transition_plugin: TransitionPlugin<GamePhase> =
+    TransitionPlugin::default()
+      .add_state(GamePhase::Flapping, Transition::wipe(Color::BLACK, 0.6))
+      .add_state(GamePhase::GameOver, Transition::cross_fade(1.))
+      .add_state(GamePhase::MainMenu, Transition::fade(Color::BLACK, 0.6));
+
   app
     .add_plugins(DefaultPlugins.set(window_plugin))
     .add_plugins(RandomPlugin)
+    .add_plugins(HighScorePlugin::new("dragon"))
     .add_plugins(asset_manager)
-    .add_plugins(GameStatePlugin::<GamePhase> {
-      game_end_state: GamePhase::GameOver,
-      game_start_state: GamePhase::Flapping,
-      menu_state: GamePhase::MainMenu,
-    })
+    .add_plugins(game_state_plugin())
+    .add_plugins(transition_plugin)
+    .add_plugins(settings_plugin)
+    .add_plugins(phase_graph)
     .run();
 
   Ok(())
 }
 
+fn add_flapping_phase(app: &mut App) {
+  app
+    .phase(GamePhase::Flapping)
+    .on_enter(setup)
+    .run_in(
+      FixedUpdate,
+      (gravity, flap, clamp, move_walls, hit_wall).chain(),
+    )
+    .on_exit(submit_result)
+    .scoped();
+}
+
 fn build_wall(
   assets: &AssetStore,
   commands: &mut Commands,
@@ -79,8 +104,7 @@ fn build_wall(
         y as f32 * 32.,
         1.,
         &loaded_assets,
-        Obstacle,
-        DragonElement
+        Obstacle
       );
     }
   }
@@ -102,14 +126,25 @@ fn clamp(
 fn flap(
   keyboard: Res<ButtonInput<KeyCode>>,
   mut query: Query<&mut Dragon>,
+  settings: Res<GameSettings>,
 ) {
-  if keyboard.pressed(KeyCode::Space)
+  if let Some(key) = settings.key("flap")
+    && keyboard.pressed(key)
     && let Ok(mut dragon) = query.single_mut()
   {
     dragon.gravity -= 0.24;
   }
 }
 
+fn game_state_plugin() -> GameStatePlugin<GamePhase> {
+  GameStatePlugin {
+    game_end_state: GamePhase::GameOver,
+    game_start_state: GamePhase::Flapping,
+    menu_controls: MenuControls::default(),
+    menu_state: GamePhase::MainMenu,
+  }
+}
+
 fn gravity(mut query: Query<(&mut Dragon, &mut Transform)>) {
   if let Ok((mut dragon, mut transform)) = query.single_mut() {
     dragon.gravity += 0.04;
@@ -141,6 +176,7 @@ fn move_walls(
   delete: Query<Entity, With<Obstacle>>,
   loaded_assets: AssetResource,
   #[allow(unused_mut)] mut rng: ResMut<RandomNumberGenerator>,
+  mut score: ResMut<Score>,
 ) {
   let mut rebuild: bool = false;
 
@@ -153,6 +189,8 @@ fn move_walls(
   }
 
   if rebuild {
+    score.0 += 1;
+
     for entity in delete.iter() {
       commands.entity(entity).despawn();
     }
@@ -172,7 +210,9 @@ fn setup(
   loaded_assets: AssetResource,
   #[allow(unused_mut)] mut rng: ResMut<RandomNumberGenerator>,
 ) {
-  commands.spawn(Camera2d).insert(DragonElement);
+  commands.spawn(Camera2d);
+
+  commands.insert_resource(Score(0));
 
   let dragon: Dragon = Dragon {
     gravity: 0.,
@@ -186,11 +226,79 @@ fn setup(
     0.,
     1.,
     &loaded_assets,
-    dragon,
-    DragonElement
+    dragon
   );
 
   let gap_y: isize = rng.range(-5..5) as isize;
 
   build_wall(&assets, &mut commands, &loaded_assets, gap_y);
 }
+
+fn submit_result(
+  mut commands: Commands,
+  score: Res<Score>,
+) {
+  commands.insert_resource(GameResult {
+    score: score.0,
+    time: None,
+    winner: None,
+  });
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use ::my_lib::testing::test_app::TestApp;
+  use ::std::time::Duration;
+
+  fn test_app() -> TestApp {
+    let mut test_app: TestApp = TestApp::new(1)
+      .with_image("dragon")
+      .with_image("wall")
+      .with_plugins(game_state_plugin());
+
+    add_flapping_phase(&mut test_app.app);
+
+    test_app
+      .app
+      .insert_resource(GameSettings::default())
+      .world_mut()
+      .resource_mut::<GameSettings>()
+      .key_bindings
+      .insert("flap".to_string(), KeyCode::Space);
+
+    test_app.advance_frames(2);
+
+    test_app
+  }
+
+  #[test]
+  fn test_dragon_falls_to_game_over() {
+    let mut test_app: TestApp = test_app();
+
+    assert_eq!(test_app.state::<GamePhase>(), GamePhase::MainMenu);
+
+    test_app.tap(KeyCode::KeyP);
+
+    assert_eq!(test_app.state::<GamePhase>(), GamePhase::Flapping);
+    assert_eq!(test_app.count::<With<Dragon>>(), 1);
+    assert!(test_app.count::<With<Obstacle>>() > 0);
+
+    test_app.advance_time(Duration::from_secs(3));
+
+    assert_eq!(test_app.state::<GamePhase>(), GamePhase::GameOver);
+    assert_eq!(test_app.count::<With<Dragon>>(), 0);
+    assert_eq!(test_app.resource::<GameResult>().score, 0);
+  }
+
+  #[test]
+  fn test_flapping_keeps_the_dragon_up() {
+    let mut test_app: TestApp = test_app();
+
+    test_app.tap(KeyCode::KeyP).press(KeyCode::Space);
+
+    test_app.advance_time(Duration::from_secs(3));
+
+    assert_eq!(test_app.state::<GamePhase>(), GamePhase::Flapping);
+  }
+}
@@ -0,0 +1,5 @@
+use ::bevy::prelude::*;
+
+/// Walls passed during the current flight
+#[derive(Resource)]
+pub struct Score(pub usize);
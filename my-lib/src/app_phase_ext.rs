@@ -0,0 +1,29 @@
+use super::phase_builder::PhaseBuilder;
+use ::bevy::prelude::*;
+use ::bevy::state::state::FreelyMutableState;
+
+/// Starts a PhaseBuilder for one phase, e.g.
+/// `app.phase(GamePhase::Flapping).on_enter(setup).run(flap)`
+pub trait AppPhaseExt {
+  fn phase<T>(
+    &mut self,
+    phase: T,
+  ) -> PhaseBuilder<'_, T>
+  where
+    T: Copy + FreelyMutableState + States;
+}
+
+impl AppPhaseExt for App {
+  fn phase<T>(
+    &mut self,
+    phase: T,
+  ) -> PhaseBuilder<'_, T>
+  where
+    T: Copy + FreelyMutableState + States,
+  {
+    PhaseBuilder {
+      app: self,
+      phase,
+    }
+  }
+}
@@ -0,0 +1,25 @@
+use ::bevy::prelude::*;
+use ::std::time::Duration;
+
+#[derive(Resource)]
+pub struct GameClock {
+  pub started: Duration,
+}
+
+impl GameClock {
+  pub fn elapsed(
+    &self,
+    time: &Time,
+  ) -> Duration {
+    time.elapsed().saturating_sub(self.started)
+  }
+}
+
+pub(crate) fn start(
+  mut commands: Commands,
+  time: Res<Time>,
+) {
+  commands.insert_resource(GameClock {
+    started: time.elapsed(),
+  });
+}
@@ -1,6 +1,10 @@
+use super::game_result::GameResult;
+use super::high_scores::high_score_table::HighScoreTable;
 use super::menu_assets::MenuAssets;
+use super::menu_controls::{self, MenuControls};
 use super::menu_element::MenuElement;
 use super::menu_resource::MenuResource;
+use super::settings::settings_screen::SettingsScreen;
 use ::bevy::state::state::FreelyMutableState;
 use ::bevy::{app::AppExit, prelude::*};
 
@@ -10,21 +14,30 @@ pub fn run<T>(
   current_state: Res<State<T>>,
   mut state: ResMut<NextState<T>>,
   menu_state: Res<MenuResource<T>>,
+  settings_screen: Option<ResMut<NextState<SettingsScreen>>>,
 ) where
   T: States + FromWorld + FreelyMutableState,
 {
   let current_state: T = current_state.get().clone();
 
+  let controls: MenuControls = menu_state.menu_controls;
+
   if current_state == menu_state.menu_state {
-    if keyboard.just_pressed(KeyCode::KeyP) {
+    if keyboard.just_pressed(controls.play) {
       state.set(menu_state.game_start_state.clone());
-    } else if keyboard.just_pressed(KeyCode::KeyQ) {
+    } else if keyboard.just_pressed(controls.quit) {
       exit.write(AppExit::Success);
+    } else if keyboard.just_pressed(controls.settings)
+      && let Some(mut settings_screen) = settings_screen
+    {
+      settings_screen.set(SettingsScreen::Open);
     }
   } else if current_state == menu_state.game_end_state {
-    if keyboard.just_pressed(KeyCode::KeyM) {
+    if keyboard.just_pressed(controls.menu) {
       state.set(menu_state.menu_state.clone());
-    } else if keyboard.just_pressed(KeyCode::KeyQ) {
+    } else if keyboard.just_pressed(controls.restart) {
+      state.set(menu_state.game_start_state.clone());
+    } else if keyboard.just_pressed(controls.quit) {
       exit.write(AppExit::Success);
     }
   }
@@ -35,6 +48,9 @@ pub fn setup<T>(
   mut commands: Commands,
   menu_resource: Res<MenuResource<T>>,
   asset_server: Res<AssetServer>,
+  game_result: Option<Res<GameResult>>,
+  high_score_table: Res<HighScoreTable>,
+  settings_screen: Option<Res<State<SettingsScreen>>>,
 ) where
   T: States + FromWorld + FreelyMutableState,
 {
@@ -66,4 +82,116 @@ pub fn setup<T>(
       Transform::from_xyz(0., 0., 1.),
     ))
     .insert(MenuElement);
+
+  let controls: MenuControls = menu_resource.menu_controls;
+
+  if menu_resource.game_end_state == *current_state {
+    spawn_results(&mut commands, game_result.as_deref(), &high_score_table);
+
+    spawn_hint(
+      &mut commands,
+      format!("{} - Restart", menu_controls::key_label(controls.restart)),
+    );
+  } else if settings_screen.is_some() {
+    spawn_hint(
+      &mut commands,
+      format!("{} - Settings", menu_controls::key_label(controls.settings)),
+    );
+  }
+}
+
+fn results_text(
+  text: String,
+  color: Color,
+) -> (Text, TextFont, TextColor) {
+  (
+    Text::new(text),
+    TextFont {
+      font_size: 20.,
+      ..default()
+    },
+    TextColor(color),
+  )
+}
+
+fn spawn_hint(
+  commands: &mut Commands,
+  hint: String,
+) {
+  commands.spawn((
+    Node {
+      bottom: Val::Px(24.),
+      position_type: PositionType::Absolute,
+      right: Val::Px(24.),
+      ..default()
+    },
+    results_text(hint, Color::WHITE),
+    MenuElement,
+  ));
+}
+
+fn spawn_results(
+  commands: &mut Commands,
+  game_result: Option<&GameResult>,
+  high_score_table: &HighScoreTable,
+) {
+  let highlight: Color = Color::srgb(1., 0.85, 0.2);
+
+  commands
+    .spawn((
+      Node {
+        flex_direction: FlexDirection::Column,
+        padding: UiRect::all(Val::Px(16.)),
+        position_type: PositionType::Absolute,
+        right: Val::Px(24.),
+        row_gap: Val::Px(4.),
+        top: Val::Px(24.),
+        ..default()
+      },
+      BackgroundColor(Color::srgba(0., 0., 0., 0.7)),
+      MenuElement,
+    ))
+    .with_children(|parent| {
+      if let Some(game_result) = game_result {
+        parent.spawn(results_text(
+          format!("Score: {}", game_result.score),
+          highlight,
+        ));
+
+        if let Some(time) = game_result.time {
+          parent.spawn(results_text(
+            format!("Time: {:.1} s", time.as_secs_f32()),
+            Color::WHITE,
+          ));
+        }
+
+        if let Some(winner) = &game_result.winner {
+          parent.spawn(results_text(format!("Winner: {winner}"), Color::WHITE));
+        }
+      }
+
+      parent.spawn(results_text("High Scores".to_string(), highlight));
+
+      high_score_table
+        .entries
+        .iter()
+        .enumerate()
+        .for_each(|(index, entry)| {
+          let color: Color = if high_score_table.latest == Some(index) {
+            highlight
+          } else {
+            Color::WHITE
+          };
+
+          let line: String = format!(
+            "{:>2}. {:>5}  {}  {}",
+            index + 1,
+            entry.score,
+            entry.date_string(),
+            entry.name,
+          );
+
+          parent.spawn(results_text(line, color));
+        });
+    });
 }
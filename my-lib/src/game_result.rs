@@ -0,0 +1,15 @@
+use ::bevy::prelude::*;
+use ::std::time::Duration;
+
+/// Inserted by a game before it reaches the game end state
+#[derive(Clone, Debug, Resource)]
+pub struct GameResult {
+  pub score: usize,
+  /// Filled in from the GameClock when the game does not supply it
+  pub time: Option<Duration>,
+  pub winner: Option<String>,
+}
+
+pub(crate) fn clear(mut commands: Commands) {
+  commands.remove_resource::<GameResult>();
+}
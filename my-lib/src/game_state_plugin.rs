@@ -1,15 +1,28 @@
 use super::bevy_assets::loading_menu;
 use super::cleanup;
+use super::game_clock;
 use super::game_menus;
+use super::game_result;
+use super::high_scores::high_score_plugin;
+use super::high_scores::high_score_table::HighScoreTable;
 use super::menu_assets;
+use super::menu_controls::MenuControls;
 use super::menu_element::MenuElement;
 use super::menu_resource::MenuResource;
+use super::pause::pause_element::PauseElement;
+use super::pause::pause_menu;
+use super::pause::pause_state::PauseState;
+use super::random_plugin;
+use super::settings::settings_screen::SettingsScreen;
 use ::bevy::prelude::*;
 use ::bevy::state::state::FreelyMutableState;
 
 pub struct GameStatePlugin<T> {
   pub game_end_state: T,
+  /// The state the game is played in.  Gameplay sub-states should use it as
+  /// their source, and the pause key works while it is active.
   pub game_start_state: T,
+  pub menu_controls: MenuControls,
   pub menu_state: T,
 }
 
@@ -28,28 +41,71 @@ where
     let start = MenuResource {
       game_end_state: self.game_end_state,
       game_start_state: self.game_start_state,
+      menu_controls: self.menu_controls,
       menu_state: self.menu_state,
     };
 
     app.insert_resource(start);
 
-    app.add_systems(OnEnter(self.menu_state), game_menus::setup::<T>);
+    app.init_resource::<HighScoreTable>();
+
+    // Games start from the menu or the game over screen, so the generator
+    // is reseeded on the way into both.  A game that continues where it
+    // left off brings its own generator along after this has run.
+    app.add_systems(
+      OnEnter(self.menu_state),
+      (random_plugin::reseed, game_menus::setup::<T>),
+    );
 
     app.add_systems(
       Update,
-      game_menus::run::<T>.run_if(in_state(self.menu_state)),
+      game_menus::run::<T>.run_if(
+        in_state(self.menu_state).and(not(in_state(SettingsScreen::Open))),
+      ),
     );
 
     app.add_systems(OnExit(self.menu_state), cleanup::<MenuElement>);
 
-    app.add_systems(OnEnter(self.game_end_state), game_menus::setup::<T>);
+    app.add_systems(OnEnter(self.game_start_state), game_clock::start);
+
+    app.init_state::<PauseState>();
+
+    app.add_systems(
+      Update,
+      pause_menu::run::<T>.run_if(in_state(self.game_start_state)),
+    );
+
+    app.add_systems(
+      OnEnter(PauseState::Paused),
+      (pause_menu::pause_time, pause_menu::setup::<T>),
+    );
+
+    app.add_systems(
+      OnExit(PauseState::Paused),
+      (pause_menu::resume_time, cleanup::<PauseElement>),
+    );
+
+    app.add_systems(OnExit(self.game_start_state), pause_menu::reset);
+
+    app.add_systems(
+      OnEnter(self.game_end_state),
+      (
+        high_score_plugin::submit_score,
+        random_plugin::reseed,
+        game_menus::setup::<T>,
+      )
+        .chain(),
+    );
 
     app.add_systems(
       Update,
       game_menus::run::<T>.run_if(in_state(self.game_end_state)),
     );
 
-    app.add_systems(OnExit(self.game_end_state), cleanup::<MenuElement>);
+    app.add_systems(
+      OnExit(self.game_end_state),
+      (cleanup::<MenuElement>, game_result::clear),
+    );
 
     app.add_systems(
       Update,
@@ -0,0 +1,68 @@
+use ::serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct HighScoreEntry {
+  /// Seconds since the Unix epoch
+  pub date: u64,
+  pub name: String,
+  pub score: usize,
+  /// The seed the game was played from, when the game used the generator
+  pub seed: Option<u64>,
+}
+
+impl HighScoreEntry {
+  /// Formats the date as YYYY-MM-DD in UTC
+  pub fn date_string(&self) -> String {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days: i64 = (self.date / 86_400) as i64 + 719_468;
+
+    let era: i64 = days.div_euclid(146_097);
+
+    let day_of_era: i64 = days - era * 146_097;
+
+    let year_of_era: i64 = (day_of_era - day_of_era / 1_460
+      + day_of_era / 36_524
+      - day_of_era / 146_096)
+      / 365;
+
+    let day_of_year: i64 =
+      day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+
+    let month_index: i64 = (5 * day_of_year + 2) / 153;
+
+    let day: i64 = day_of_year - (153 * month_index + 2) / 5 + 1;
+
+    let month: i64 = if month_index < 10 {
+      month_index + 3
+    } else {
+      month_index - 9
+    };
+
+    let year: i64 = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn entry(date: u64) -> HighScoreEntry {
+    HighScoreEntry {
+      date,
+      name: "test".to_string(),
+      score: 0,
+      seed: None,
+    }
+  }
+
+  #[test]
+  fn test_date_string() {
+    assert_eq!(entry(0).date_string(), "1970-01-01");
+
+    assert_eq!(entry(951_782_400).date_string(), "2000-02-29");
+
+    assert_eq!(entry(1_764_460_800).date_string(), "2025-11-30");
+  }
+}
@@ -0,0 +1,148 @@
+use super::super::persistence;
+use super::high_score_entry::HighScoreEntry;
+use ::serde::{Deserialize, Serialize};
+use ::std::collections::BTreeMap;
+use ::std::path::{Path, PathBuf};
+
+pub const FILE_VERSION: u32 = 1;
+
+/// The on-disk format shared by every game, keyed by game name
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HighScoreFile {
+  pub games: BTreeMap<String, Vec<HighScoreEntry>>,
+  pub version: u32,
+}
+
+impl HighScoreFile {
+  /// The high-score file in the platform data directory, if there is one
+  pub fn default_path() -> Option<PathBuf> {
+    persistence::data_path("high-scores.json")
+  }
+
+  /// A missing or corrupt file loads as empty.  A file from a newer version
+  /// is an error so that the caller does not replace it.
+  pub fn load(path: &Path) -> ::anyhow::Result<Self> {
+    let Some(file) = persistence::load_json::<Self>(path) else {
+      return Ok(Self::default());
+    };
+
+    if file.version > FILE_VERSION {
+      ::anyhow::bail!(
+        "{} is version {} but only version {FILE_VERSION} is supported",
+        path.display(),
+        file.version,
+      );
+    }
+
+    Ok(file)
+  }
+
+  pub fn save(
+    &self,
+    path: &Path,
+  ) -> ::anyhow::Result<()> {
+    persistence::save_json(self, path)
+  }
+}
+
+impl Default for HighScoreFile {
+  fn default() -> Self {
+    Self {
+      games: BTreeMap::new(),
+      version: FILE_VERSION,
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use ::std::fs;
+
+  /// A directory of the test's own, since the tests run in parallel and
+  /// each removes its directory when it is done
+  fn test_directory(test: &str) -> PathBuf {
+    let directory: PathBuf = ::std::env::temp_dir().join(format!(
+      "my-lib-high-score-file-{}-{test}",
+      ::std::process::id()
+    ));
+
+    fs::create_dir_all(&directory).unwrap();
+
+    directory
+  }
+
+  #[test]
+  fn test_corrupt_file_is_moved_aside() {
+    let directory: PathBuf = test_directory("corrupt");
+
+    let path: PathBuf = directory.join("corrupt.json");
+
+    fs::write(&path, "{ not json").unwrap();
+
+    let file: HighScoreFile = HighScoreFile::load(&path).unwrap();
+
+    assert!(file.games.is_empty());
+
+    assert!(!path.exists());
+
+    assert!(directory.join("corrupt.json.corrupt").exists());
+
+    fs::remove_dir_all(&directory).unwrap();
+  }
+
+  #[test]
+  fn test_missing_file_is_empty() {
+    let directory: PathBuf = test_directory("missing");
+
+    let file: HighScoreFile =
+      HighScoreFile::load(&directory.join("missing.json")).unwrap();
+
+    assert!(file.games.is_empty());
+
+    assert_eq!(file.version, FILE_VERSION);
+
+    fs::remove_dir_all(&directory).unwrap();
+  }
+
+  #[test]
+  fn test_newer_version_is_rejected() {
+    let directory: PathBuf = test_directory("newer");
+
+    let path: PathBuf = directory.join("newer.json");
+
+    fs::write(&path, r#"{ "games": {}, "version": 999 }"#).unwrap();
+
+    assert!(HighScoreFile::load(&path).is_err());
+
+    assert!(path.exists());
+
+    fs::remove_dir_all(&directory).unwrap();
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let directory: PathBuf = test_directory("round-trip");
+
+    let path: PathBuf = directory.join("round-trip.json");
+
+    let entry: HighScoreEntry = HighScoreEntry {
+      date: 1_764_460_800,
+      name: "Player".to_string(),
+      score: 42,
+      seed: Some(7),
+    };
+
+    let mut file: HighScoreFile = HighScoreFile::default();
+
+    file.games.insert("pig".to_string(), vec![entry.clone()]);
+
+    file.save(&path).unwrap();
+
+    let loaded: HighScoreFile = HighScoreFile::load(&path).unwrap();
+
+    assert_eq!(loaded.games["pig"], vec![entry]);
+
+    fs::remove_dir_all(&directory).unwrap();
+  }
+}
@@ -0,0 +1,75 @@
+use super::super::game_clock::GameClock;
+use super::super::game_result::GameResult;
+use super::super::random::RandomNumberGenerator;
+use super::high_score_entry::HighScoreEntry;
+use super::high_score_file::HighScoreFile;
+use super::high_score_table::HighScoreTable;
+use ::bevy::prelude::*;
+use ::std::path::PathBuf;
+use ::std::time::{SystemTime, UNIX_EPOCH};
+
+/// Loads the game's persisted high scores at startup.  Without this plugin
+/// the GameStatePlugin keeps an in-memory table.
+pub struct HighScorePlugin {
+  pub game: String,
+  pub path: Option<PathBuf>,
+}
+
+impl HighScorePlugin {
+  pub fn new<S: ToString>(game: S) -> Self {
+    Self {
+      game: game.to_string(),
+      path: HighScoreFile::default_path(),
+    }
+  }
+}
+
+impl Plugin for HighScorePlugin {
+  fn build(
+    &self,
+    app: &mut App,
+  ) {
+    app.insert_resource(HighScoreTable::load(&self.game, self.path.clone()));
+  }
+}
+
+/// Submits the GameResult, if the game supplied one, at game end
+pub fn submit_score(
+  game_clock: Option<Res<GameClock>>,
+  game_result: Option<ResMut<GameResult>>,
+  mut high_score_table: ResMut<HighScoreTable>,
+  rng: Option<Res<RandomNumberGenerator>>,
+  time: Res<Time>,
+) {
+  high_score_table.latest = None;
+
+  let Some(mut game_result) = game_result else {
+    return;
+  };
+
+  if game_result.time.is_none()
+    && let Some(game_clock) = game_clock
+  {
+    game_result.time = Some(game_clock.elapsed(&time));
+  }
+
+  let date: u64 = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_secs())
+    .unwrap_or_default();
+
+  let entry: HighScoreEntry = HighScoreEntry {
+    date,
+    name: high_score_table.player_name.clone(),
+    score: game_result.score,
+    seed: rng.map(|rng| rng.seed()),
+  };
+
+  high_score_table.latest = high_score_table.submit(entry);
+
+  if high_score_table.latest.is_some()
+    && let Err(error) = high_score_table.save()
+  {
+    warn!("Could not save high scores: {error}");
+  }
+}
@@ -0,0 +1,174 @@
+use super::high_score_entry::HighScoreEntry;
+use super::high_score_file::HighScoreFile;
+use ::bevy::prelude::*;
+use ::std::env;
+use ::std::path::PathBuf;
+
+pub const MAX_ENTRIES: usize = 10;
+
+/// The ranked entries for one game.  Without a path the table is kept in
+/// memory only.
+#[derive(Resource)]
+pub struct HighScoreTable {
+  pub entries: Vec<HighScoreEntry>,
+  pub game: String,
+  /// Rank of the most recent submission if it made the table
+  pub latest: Option<usize>,
+  pub path: Option<PathBuf>,
+  pub player_name: String,
+}
+
+impl HighScoreTable {
+  pub fn load(
+    game: &str,
+    path: Option<PathBuf>,
+  ) -> Self {
+    let mut table: Self = Self {
+      game: game.to_string(),
+      ..default()
+    };
+
+    let Some(path) = path else {
+      return table;
+    };
+
+    match HighScoreFile::load(&path) {
+      Ok(mut file) => {
+        table.entries = file.games.remove(game).unwrap_or_default();
+
+        table.entries.truncate(MAX_ENTRIES);
+
+        table.path = Some(path);
+      },
+      Err(error) => warn!("High scores will not be saved: {error}"),
+    }
+
+    table
+  }
+
+  /// Merges this game's entries into the file so that the other games'
+  /// entries are preserved
+  pub fn save(&self) -> ::anyhow::Result<()> {
+    let Some(path) = &self.path else {
+      return Ok(());
+    };
+
+    let mut file: HighScoreFile = HighScoreFile::load(path)?;
+
+    file.games.insert(self.game.clone(), self.entries.clone());
+
+    file.save(path)
+  }
+
+  /// Returns the zero-based rank if the entry made the table
+  pub fn submit(
+    &mut self,
+    entry: HighScoreEntry,
+  ) -> Option<usize> {
+    let rank: usize = self
+      .entries
+      .iter()
+      .position(|existing| existing.score < entry.score)
+      .unwrap_or(self.entries.len());
+
+    if rank >= MAX_ENTRIES {
+      return None;
+    }
+
+    self.entries.insert(rank, entry);
+
+    self.entries.truncate(MAX_ENTRIES);
+
+    Some(rank)
+  }
+}
+
+impl Default for HighScoreTable {
+  fn default() -> Self {
+    let player_name: String = env::var("USER")
+      .or_else(|_| env::var("USERNAME"))
+      .unwrap_or_else(|_| "Player".to_string());
+
+    Self {
+      entries: Vec::new(),
+      game: String::new(),
+      latest: None,
+      path: None,
+      player_name,
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use ::std::fs;
+
+  fn entry(score: usize) -> HighScoreEntry {
+    HighScoreEntry {
+      date: 0,
+      name: "test".to_string(),
+      score,
+      seed: None,
+    }
+  }
+
+  #[test]
+  fn test_save_preserves_other_games() {
+    let path: PathBuf = env::temp_dir().join(format!(
+      "my-lib-high-score-table-{}.json",
+      ::std::process::id()
+    ));
+
+    let mut dragon: HighScoreTable =
+      HighScoreTable::load("dragon", Some(path.clone()));
+
+    dragon.submit(entry(3));
+
+    dragon.save().unwrap();
+
+    let mut pig: HighScoreTable =
+      HighScoreTable::load("pig", Some(path.clone()));
+
+    assert!(pig.entries.is_empty());
+
+    pig.submit(entry(100));
+
+    pig.save().unwrap();
+
+    let dragon: HighScoreTable =
+      HighScoreTable::load("dragon", Some(path.clone()));
+
+    assert_eq!(dragon.entries, vec![entry(3)]);
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  fn test_submit_keeps_top_entries() {
+    let mut table: HighScoreTable = Default::default();
+
+    for score in 0..20 {
+      table.submit(entry(score));
+    }
+
+    assert_eq!(table.entries.len(), MAX_ENTRIES);
+
+    assert_eq!(table.entries[0].score, 19);
+
+    assert_eq!(table.entries[MAX_ENTRIES - 1].score, 10);
+
+    assert_eq!(table.submit(entry(5)), None);
+  }
+
+  #[test]
+  fn test_submit_ranks_ties_after_earlier_entries() {
+    let mut table: HighScoreTable = Default::default();
+
+    assert_eq!(table.submit(entry(50)), Some(0));
+
+    assert_eq!(table.submit(entry(50)), Some(1));
+
+    assert_eq!(table.submit(entry(70)), Some(0));
+  }
+}
@@ -0,0 +1,4 @@
+pub mod high_score_entry;
+pub mod high_score_file;
+pub mod high_score_plugin;
+pub mod high_score_table;
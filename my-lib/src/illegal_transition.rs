@@ -0,0 +1,7 @@
+/// What a PhaseGraph does about an undeclared transition in a debug build
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum IllegalTransition {
+  #[default]
+  Log,
+  Panic,
+}
@@ -6,41 +6,117 @@ pub mod random;
 #[cfg(feature = "locking")]
 pub mod random_locking;
 
+pub mod app_phase_ext;
 pub mod bevy_assets;
+pub mod game_clock;
 pub mod game_menus;
+pub mod game_result;
 pub mod game_state_plugin;
+pub mod high_scores;
+pub mod illegal_transition;
 pub mod menu_assets;
+pub mod menu_controls;
 pub mod menu_element;
 pub mod menu_resource;
+pub mod pause;
+pub mod persistence;
+pub mod phase_builder;
+pub mod phase_graph;
+pub mod phase_set;
+pub mod phase_timer;
 pub mod random_plugin;
+pub mod settings;
+pub mod spawned_in;
+pub mod state_scope_plugin;
+pub mod testing;
+pub mod transitions;
+pub mod unscoped;
 
 #[cfg(feature = "locking")]
 pub use random_locking as random;
 
+/// Registers the systems for a game phase.  Each run group goes into Update
+/// unless it names a schedule, e.g. `run(FixedUpdate) => [ gravity ]`, and
+/// may add run conditions after the schedule, e.g.
+/// `run(Update, resource_exists::<Scores>) => [ display_score ]`.  A group
+/// marked `chain`, e.g. `run chain => [ cpu, check_game_over ]`, runs its
+/// systems in the listed order.  Every group is in the phase's PhaseSet.
 #[macro_export]
 macro_rules! add_phase {
+  (@order chain $systems:expr) => {
+    $systems.chain()
+  };
+
+  (@order $systems:expr) => {
+    $systems
+  };
+
+  (@run $app:expr, $phase:expr, [ $($mode:ident)? ], [ ] $(, $rest:expr)*) => {};
+
+  (
+    @run
+    $app:expr,
+    $phase:expr,
+    [ $($mode:ident)? ],
+    [ $($run:expr),+ ]
+  ) => {
+    $crate::add_phase!(
+      @run
+      $app,
+      $phase,
+      [ $($mode)? ],
+      [ $($run),+ ],
+      bevy::prelude::Update
+    )
+  };
+
+  (
+    @run
+    $app:expr,
+    $phase:expr,
+    [ $($mode:ident)? ],
+    [ $($run:expr),+ ],
+    $schedule:expr
+    $(, $condition:expr)*
+  ) => {
+    $app.add_systems(
+      $schedule,
+      $crate::add_phase!(@order $($mode)? ($($run,)+))
+        .in_set($crate::phase_set::PhaseSet($phase))
+        .run_if(bevy::prelude::in_state($phase))
+        $(.run_if($condition))*
+    );
+  };
+
   (
     $app:expr,
     $type:ty,
     $phase:expr,
     start => [ $($start:expr),* ],
-    run => [ $($run:expr),* ],
+    $(
+      run $(($schedule:expr $(, $condition:expr)*))? $($mode:ident)?
+        => [ $($run:expr),* ],
+    )+
     exit => [ $($exit:expr),* ]
   ) => {
     $($app.add_systems(
       bevy::prelude::OnEnter::<$type>($phase),
       $start);)*
 
-    $($app.add_systems(
-      bevy::prelude::Update,
-      $run.run_if(in_state($phase))
-    );)*
+    $($crate::add_phase!(
+      @run
+      $app,
+      $phase,
+      [ $($mode)? ],
+      [ $($run),* ]
+      $(, $schedule $(, $condition)*)?
+    );)+
 
     $($app.add_systems(
       bevy::prelude::OnExit::<$type>($phase),
       $exit
     );)*
-  }
+  };
 }
 
 #[macro_export]
@@ -67,6 +143,8 @@ macro_rules! spawn_image {
   };
 }
 
+/// Despawns every entity marked with T along with its descendants.  A marked
+/// entity whose ancestor is also marked is simply despawned with it.
 pub fn cleanup<T>(
   query: Query<Entity, With<T>>,
   mut commands: Commands,
@@ -75,5 +153,112 @@ pub fn cleanup<T>(
 {
   query
     .iter()
-    .for_each(|entity| commands.entity(entity).despawn());
+    .for_each(|entity| commands.entity(entity).try_despawn());
+}
+
+#[cfg(test)]
+mod test {
+  use super::phase_set::PhaseSet;
+  use ::bevy::prelude::*;
+  use ::bevy::state::app::StatesPlugin;
+
+  #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, States)]
+  enum Phase {
+    #[default]
+    Idle,
+    Running,
+  }
+
+  #[derive(Default, Resource)]
+  struct Counter(usize);
+
+  #[derive(Default, Resource)]
+  struct Order(Vec<usize>);
+
+  #[derive(Resource)]
+  struct Enabled;
+
+  fn count(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+  }
+
+  fn first(mut order: ResMut<Order>) {
+    order.0.push(1);
+  }
+
+  fn second(mut order: ResMut<Order>) {
+    order.0.push(2);
+  }
+
+  fn third(mut order: ResMut<Order>) {
+    order.0.push(3);
+  }
+
+  #[test]
+  fn test_add_phase_schedule_and_conditions() {
+    let mut app: App = App::new();
+
+    app
+      .add_plugins((MinimalPlugins, StatesPlugin))
+      .init_state::<Phase>()
+      .init_resource::<Counter>();
+
+    add_phase!(app, Phase, Phase::Running,
+      start => [ ],
+      run(PostUpdate, resource_exists::<Enabled>) => [ count ],
+      exit => [ ]
+    );
+
+    app.update();
+
+    app
+      .world_mut()
+      .resource_mut::<NextState<Phase>>()
+      .set(Phase::Running);
+
+    app.update();
+
+    assert_eq!(app.world().resource::<Counter>().0, 0);
+
+    app.insert_resource(Enabled);
+
+    app.update();
+
+    assert_eq!(app.world().resource::<Counter>().0, 1);
+  }
+
+  #[test]
+  fn test_add_phase_chain_and_phase_set() {
+    let mut app: App = App::new();
+
+    app
+      .add_plugins((MinimalPlugins, StatesPlugin))
+      .init_state::<Phase>()
+      .init_resource::<Order>()
+      .add_systems(Update, first.before(PhaseSet(Phase::Running)));
+
+    add_phase!(app, Phase, Phase::Running,
+      start => [ ],
+      run chain => [ second, third ],
+      exit => [ ]
+    );
+
+    app
+      .world_mut()
+      .resource_mut::<NextState<Phase>>()
+      .set(Phase::Running);
+
+    app.update();
+
+    app.world_mut().resource_mut::<Order>().0.clear();
+
+    (0..10).for_each(|_| app.update());
+
+    let order: &Vec<usize> = &app.world().resource::<Order>().0;
+
+    assert!(order.chunks(3).all(|chunk| chunk
+      == [
+        1, 2, 3
+      ]));
+  }
 }
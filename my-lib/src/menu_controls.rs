@@ -0,0 +1,55 @@
+use ::bevy::prelude::*;
+
+/// Keys for the main menu, pause and game over screen actions.  The defaults
+/// match the text in main-menu.png and game-over.png.
+#[derive(Clone, Copy, Debug)]
+pub struct MenuControls {
+  /// Returns from the game over or pause screen to the main menu
+  pub menu: KeyCode,
+  /// Pauses and resumes the game in progress
+  pub pause: KeyCode,
+  pub play: KeyCode,
+  pub quit: KeyCode,
+  /// Starts a new game directly from the game over screen
+  pub restart: KeyCode,
+  /// Opens the settings screen from the main menu
+  pub settings: KeyCode,
+}
+
+impl Default for MenuControls {
+  fn default() -> Self {
+    Self {
+      menu: KeyCode::KeyM,
+      pause: KeyCode::Escape,
+      play: KeyCode::KeyP,
+      quit: KeyCode::KeyQ,
+      restart: KeyCode::KeyR,
+      settings: KeyCode::KeyS,
+    }
+  }
+}
+
+/// A short name for showing a key on screen, such as "P" for KeyCode::KeyP
+pub fn key_label(key: KeyCode) -> String {
+  let name: String = format!("{key:?}");
+
+  name
+    .strip_prefix("Key")
+    .or_else(|| name.strip_prefix("Digit"))
+    .unwrap_or(&name)
+    .to_string()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_key_label() {
+    assert_eq!(key_label(KeyCode::KeyP), "P");
+
+    assert_eq!(key_label(KeyCode::Digit1), "1");
+
+    assert_eq!(key_label(KeyCode::Space), "Space");
+  }
+}
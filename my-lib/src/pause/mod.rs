@@ -0,0 +1,3 @@
+pub mod pause_element;
+pub mod pause_menu;
+pub mod pause_state;
@@ -0,0 +1,107 @@
+use super::super::menu_controls::{self, MenuControls};
+use super::super::menu_resource::MenuResource;
+use super::pause_element::PauseElement;
+use super::pause_state::PauseState;
+use ::bevy::app::AppExit;
+use ::bevy::prelude::*;
+use ::bevy::state::state::FreelyMutableState;
+
+const HIGHLIGHT: Color = Color::srgb(1., 0.85, 0.2);
+
+pub(crate) fn pause_time(mut time: ResMut<Time<Virtual>>) {
+  time.pause();
+}
+
+pub(crate) fn reset(mut pause_state: ResMut<NextState<PauseState>>) {
+  pause_state.set(PauseState::Running);
+}
+
+pub(crate) fn resume_time(mut time: ResMut<Time<Virtual>>) {
+  time.unpause();
+}
+
+pub(crate) fn run<T>(
+  keyboard: Res<ButtonInput<KeyCode>>,
+  mut exit: MessageWriter<AppExit>,
+  menu_resource: Res<MenuResource<T>>,
+  current_pause_state: Res<State<PauseState>>,
+  mut pause_state: ResMut<NextState<PauseState>>,
+  mut state: ResMut<NextState<T>>,
+) where
+  T: FreelyMutableState + FromWorld + States,
+{
+  let controls: MenuControls = menu_resource.menu_controls;
+
+  match current_pause_state.get() {
+    PauseState::Running => {
+      if keyboard.just_pressed(controls.pause) {
+        pause_state.set(PauseState::Paused);
+      }
+    },
+    PauseState::Paused => {
+      if keyboard.just_pressed(controls.pause) {
+        pause_state.set(PauseState::Running);
+      } else if keyboard.just_pressed(controls.menu) {
+        // The pause is reset once the game start state exits, so the game
+        // stays frozen behind any transition to the menu, which runs on
+        // real time
+        state.set(menu_resource.menu_state.clone());
+      } else if keyboard.just_pressed(controls.quit) {
+        exit.write(AppExit::Success);
+      }
+    },
+  }
+}
+
+pub(crate) fn setup<T>(
+  mut commands: Commands,
+  menu_resource: Res<MenuResource<T>>,
+) where
+  T: States,
+{
+  let controls: MenuControls = menu_resource.menu_controls;
+
+  let hints: String = format!(
+    "{} - Resume   {} - Main Menu   {} - Quit",
+    menu_controls::key_label(controls.pause),
+    menu_controls::key_label(controls.menu),
+    menu_controls::key_label(controls.quit),
+  );
+
+  commands
+    .spawn((
+      Node {
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Column,
+        height: Val::Percent(100.),
+        justify_content: JustifyContent::Center,
+        position_type: PositionType::Absolute,
+        row_gap: Val::Px(16.),
+        width: Val::Percent(100.),
+        ..default()
+      },
+      BackgroundColor(Color::srgba(0., 0., 0., 0.6)),
+      GlobalZIndex(1_000),
+      PauseElement,
+    ))
+    .with_children(|parent| {
+      parent.spawn(pause_text("Paused", 36., HIGHLIGHT));
+
+      parent.spawn(pause_text(&hints, 20., Color::WHITE));
+    });
+}
+
+fn pause_text(
+  text: &str,
+  font_size: f32,
+  color: Color,
+) -> (Text, TextFont, TextColor) {
+  (
+    Text::new(text),
+    TextFont {
+      font_size,
+      ..default()
+    },
+    TextColor(color),
+  )
+}
@@ -0,0 +1,11 @@
+use ::bevy::prelude::*;
+
+/// Whether the game in progress is paused.  GameStatePlugin keeps this at
+/// Running outside the game start state, and virtual time stands still while
+/// it is Paused.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, States)]
+pub enum PauseState {
+  Paused,
+  #[default]
+  Running,
+}
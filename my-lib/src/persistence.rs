@@ -0,0 +1,85 @@
+use ::bevy::log::warn;
+use ::serde::Serialize;
+use ::serde::de::DeserializeOwned;
+use ::std::fs;
+use ::std::io::ErrorKind;
+use ::std::path::{Path, PathBuf};
+
+const APPLICATION_DIRECTORY: &str = "book-advanced";
+
+/// A file in the platform configuration directory, if there is one
+pub fn config_path(file_name: &str) -> Option<PathBuf> {
+  dirs::config_dir().map(|config_directory| {
+    config_directory.join(APPLICATION_DIRECTORY).join(file_name)
+  })
+}
+
+/// A file in the platform data directory, if there is one
+pub fn data_path(file_name: &str) -> Option<PathBuf> {
+  dirs::data_dir().map(|data_directory| {
+    data_directory.join(APPLICATION_DIRECTORY).join(file_name)
+  })
+}
+
+/// Returns None for a missing or unreadable file.  A corrupt file is renamed
+/// with a ".corrupt" suffix so that it is kept but not overwritten.
+pub fn load_json<T>(path: &Path) -> Option<T>
+where
+  T: DeserializeOwned,
+{
+  let contents: String = match fs::read_to_string(path) {
+    Ok(contents) => contents,
+    Err(error) if error.kind() == ErrorKind::NotFound => return None,
+    Err(error) => {
+      warn!("Could not read {}: {error}", path.display());
+
+      return None;
+    },
+  };
+
+  match ::serde_json::from_str(&contents) {
+    Ok(value) => Some(value),
+    Err(error) => {
+      warn!("Corrupt file {}: {error}", path.display());
+
+      if let Err(error) = fs::rename(path, with_suffix(path, ".corrupt")) {
+        warn!("Could not move aside {}: {error}", path.display());
+      }
+
+      None
+    },
+  }
+}
+
+/// Writes to a temporary file first so that a crash cannot truncate the
+/// existing file
+pub fn save_json<T>(
+  value: &T,
+  path: &Path,
+) -> ::anyhow::Result<()>
+where
+  T: Serialize,
+{
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent)?;
+  }
+
+  let temporary_path: PathBuf = with_suffix(path, ".tmp");
+
+  fs::write(&temporary_path, ::serde_json::to_string_pretty(value)?)?;
+
+  fs::rename(&temporary_path, path)?;
+
+  Ok(())
+}
+
+fn with_suffix(
+  path: &Path,
+  suffix: &str,
+) -> PathBuf {
+  let mut path_with_suffix = path.as_os_str().to_owned();
+
+  path_with_suffix.push(suffix);
+
+  path_with_suffix.into()
+}
@@ -0,0 +1,169 @@
+use super::phase_set::PhaseSet;
+use super::phase_timer::{self, PhaseTimer};
+use super::state_scope_plugin::StateScopePlugin;
+use ::bevy::ecs::schedule::{IntoScheduleConfigs, ScheduleLabel};
+use ::bevy::ecs::system::ScheduleSystem;
+use ::bevy::prelude::*;
+use ::bevy::state::state::FreelyMutableState;
+
+/// Registers the systems for a game phase one section at a time.  Every
+/// section is optional, and run groups are in the phase's PhaseSet just like
+/// the ones add_phase! registers.
+pub struct PhaseBuilder<'a, T>
+where
+  T: States,
+{
+  pub(crate) app: &'a mut App,
+  pub(crate) phase: T,
+}
+
+impl<T> PhaseBuilder<'_, T>
+where
+  T: Copy + FreelyMutableState + States,
+{
+  pub fn on_enter<M>(
+    self,
+    systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+  ) -> Self {
+    self.app.add_systems(OnEnter(self.phase), systems);
+
+    self
+  }
+
+  pub fn on_exit<M>(
+    self,
+    systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+  ) -> Self {
+    self.app.add_systems(OnExit(self.phase), systems);
+
+    self
+  }
+
+  /// Runs the systems in Update while the phase is active
+  pub fn run<M>(
+    self,
+    systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+  ) -> Self {
+    self.run_in(Update, systems)
+  }
+
+  /// Runs the systems in `schedule` while the phase is active
+  pub fn run_in<M>(
+    self,
+    schedule: impl ScheduleLabel,
+    systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+  ) -> Self {
+    self.app.add_systems(
+      schedule,
+      systems
+        .in_set(PhaseSet(self.phase))
+        .run_if(in_state(self.phase)),
+    );
+
+    self
+  }
+
+  /// Despawns every visible top-level entity spawned while the phase is
+  /// active when the phase exits, by adding a default StateScopePlugin for
+  /// the phase's state unless the app already has one.  The plugin scopes
+  /// every state of that type, so an app that needs groups or kept states
+  /// adds its own before building the phase.  Entities marked Unscoped are
+  /// left alone.
+  pub fn scoped(self) -> Self {
+    if !self.app.is_plugin_added::<StateScopePlugin<T>>() {
+      self.app.add_plugins(StateScopePlugin::<T>::default());
+    }
+
+    self
+  }
+
+  /// Moves to `next` after `seconds` in the phase, unless the phase has
+  /// already been left
+  pub fn timeout(
+    self,
+    seconds: f32,
+    next: T,
+  ) -> Self {
+    self
+      .app
+      .add_systems(OnEnter(self.phase), move |mut commands: Commands| {
+        commands.insert_resource(PhaseTimer {
+          next,
+          timer: Timer::from_seconds(seconds, TimerMode::Once),
+        });
+      });
+
+    self
+      .on_exit(phase_timer::remove::<T>)
+      .run(phase_timer::tick::<T>)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::super::app_phase_ext::AppPhaseExt;
+  use super::super::unscoped::Unscoped;
+  use super::*;
+  use ::bevy::state::app::StatesPlugin;
+  use ::bevy::time::TimeUpdateStrategy;
+  use ::std::time::Duration;
+
+  #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, States)]
+  enum Phase {
+    #[default]
+    Idle,
+    Running,
+  }
+
+  #[derive(Component)]
+  struct Marker;
+
+  fn spawn_markers(mut commands: Commands) {
+    commands
+      .spawn((Marker, Visibility::default()))
+      .with_child(Marker);
+    commands.spawn((Marker, Unscoped, Visibility::default()));
+  }
+
+  fn markers(app: &mut App) -> usize {
+    app
+      .world_mut()
+      .query_filtered::<(), With<Marker>>()
+      .iter(app.world())
+      .count()
+  }
+
+  #[test]
+  fn test_phase_builder_scoped_timeout() {
+    let mut app: App = App::new();
+
+    app
+      .add_plugins((MinimalPlugins, StatesPlugin))
+      .insert_resource(TimeUpdateStrategy::ManualDuration(
+        Duration::from_millis(200),
+      ))
+      .init_state::<Phase>();
+
+    app
+      .phase(Phase::Running)
+      .on_enter(spawn_markers)
+      .scoped()
+      .timeout(0.5, Phase::Idle);
+
+    app
+      .world_mut()
+      .resource_mut::<NextState<Phase>>()
+      .set(Phase::Running);
+
+    app.update();
+
+    assert_eq!(markers(&mut app), 3);
+    assert!(app.world().contains_resource::<PhaseTimer<Phase>>());
+
+    (0..5).for_each(|_| app.update());
+
+    assert_eq!(*app.world().resource::<State<Phase>>().get(), Phase::Idle);
+    assert_eq!(markers(&mut app), 1);
+    assert!(!app.world().contains_resource::<PhaseTimer<Phase>>());
+  }
+}
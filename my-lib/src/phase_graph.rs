@@ -0,0 +1,280 @@
+use super::illegal_transition::IllegalTransition;
+use ::bevy::prelude::*;
+use ::std::any::type_name;
+use ::std::env;
+use ::std::fmt::Write;
+
+/// The allowed transitions between the phases of a game.  As a plugin it
+/// checks every transition in debug builds.
+#[derive(Clone, Resource)]
+pub struct PhaseGraph<T> {
+  edges: Vec<(T, T)>,
+  on_illegal: IllegalTransition,
+}
+
+impl<T> PhaseGraph<T>
+where
+  T: States,
+{
+  pub fn allow(
+    mut self,
+    from: T,
+    to: T,
+  ) -> Self {
+    if !self.is_allowed(&from, &to) {
+      self.edges.push((from, to));
+    }
+
+    self
+  }
+
+  /// Phases with no transition out of them
+  pub fn dead_ends(&self) -> Vec<T> {
+    self
+      .phases()
+      .into_iter()
+      .filter(|phase| self.edges.iter().all(|(from, _)| from != phase))
+      .collect()
+  }
+
+  pub fn is_allowed(
+    &self,
+    from: &T,
+    to: &T,
+  ) -> bool {
+    self
+      .edges
+      .iter()
+      .any(|edge| edge.0 == *from && edge.1 == *to)
+  }
+
+  pub fn on_illegal(
+    mut self,
+    on_illegal: IllegalTransition,
+  ) -> Self {
+    self.on_illegal = on_illegal;
+
+    self
+  }
+
+  /// Every phase named by a transition, in the order first declared
+  pub fn phases(&self) -> Vec<T> {
+    let mut phases: Vec<T> = Vec::new();
+
+    for (from, to) in &self.edges {
+      for phase in [
+        from, to,
+      ] {
+        if !phases.contains(phase) {
+          phases.push(phase.clone());
+        }
+      }
+    }
+
+    phases
+  }
+
+  /// Prints a diagram and returns true if the program was run with
+  /// --graphviz or --mermaid
+  pub fn print_requested_diagram(&self) -> bool
+  where
+    T: Default,
+  {
+    let diagram: Option<String> =
+      env::args().find_map(|argument| match argument.as_str() {
+        "--graphviz" => Some(self.to_graphviz()),
+        "--mermaid" => Some(self.to_mermaid()),
+        _ => None,
+      });
+
+    if let Some(diagram) = &diagram {
+      print!("{diagram}");
+    }
+
+    diagram.is_some()
+  }
+
+  pub fn to_graphviz(&self) -> String {
+    let mut graph: String = format!("digraph {} {{\n", short_type_name::<T>());
+
+    for (from, to) in &self.edges {
+      writeln!(graph, "  {from:?} -> {to:?};").unwrap();
+    }
+
+    graph.push_str("}\n");
+
+    graph
+  }
+
+  /// A Mermaid state diagram that starts from the default phase
+  pub fn to_mermaid(&self) -> String
+  where
+    T: Default,
+  {
+    let mut diagram: String = "stateDiagram-v2\n".to_string();
+
+    writeln!(diagram, "  [*] --> {:?}", T::default()).unwrap();
+
+    for (from, to) in &self.edges {
+      writeln!(diagram, "  {from:?} --> {to:?}").unwrap();
+    }
+
+    diagram
+  }
+
+  /// Phases that cannot be reached from the default phase
+  pub fn unreachable(&self) -> Vec<T>
+  where
+    T: Default,
+  {
+    let mut reached: Vec<T> = vec![T::default()];
+
+    let mut index: usize = 0;
+
+    while index < reached.len() {
+      let current: T = reached[index].clone();
+
+      for (from, to) in &self.edges {
+        if *from == current && !reached.contains(to) {
+          reached.push(to.clone());
+        }
+      }
+
+      index += 1;
+    }
+
+    self
+      .phases()
+      .into_iter()
+      .filter(|phase| !reached.contains(phase))
+      .collect()
+  }
+}
+
+impl<T> Default for PhaseGraph<T> {
+  fn default() -> Self {
+    Self {
+      edges: Vec::new(),
+      on_illegal: IllegalTransition::default(),
+    }
+  }
+}
+
+impl<T> Plugin for PhaseGraph<T>
+where
+  T: States,
+{
+  fn build(
+    &self,
+    app: &mut App,
+  ) {
+    app.insert_resource(self.clone());
+
+    #[cfg(debug_assertions)]
+    app.add_systems(Last, validate::<T>);
+  }
+}
+
+fn short_type_name<T>() -> &'static str {
+  let name: &str = type_name::<T>();
+
+  name.rsplit("::").next().unwrap_or(name)
+}
+
+#[cfg_attr(
+  not(debug_assertions),
+  expect(dead_code)
+)]
+fn validate<T>(
+  phase_graph: Res<PhaseGraph<T>>,
+  mut reader: MessageReader<StateTransitionEvent<T>>,
+) where
+  T: States,
+{
+  for event in reader.read() {
+    let (Some(from), Some(to)) = (&event.exited, &event.entered) else {
+      continue;
+    };
+
+    if (from == to && !event.allow_same_state_transitions)
+      || phase_graph.is_allowed(from, to)
+    {
+      continue;
+    }
+
+    let message: String = format!(
+      "Illegal {} transition from {from:?} to {to:?}",
+      short_type_name::<T>()
+    );
+
+    match phase_graph.on_illegal {
+      IllegalTransition::Log => error!("{message}"),
+      IllegalTransition::Panic => panic!("{message}"),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use ::bevy::state::app::StatesPlugin;
+
+  #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, States)]
+  enum Phase {
+    Orphan,
+    Over,
+    Playing,
+    #[default]
+    Start,
+  }
+
+  fn graph() -> PhaseGraph<Phase> {
+    PhaseGraph::default()
+      .allow(Phase::Start, Phase::Playing)
+      .allow(Phase::Playing, Phase::Over)
+      .allow(Phase::Orphan, Phase::Over)
+      .on_illegal(IllegalTransition::Panic)
+  }
+
+  #[test]
+  fn test_analysis() {
+    assert_eq!(graph().dead_ends(), vec![Phase::Over]);
+
+    assert_eq!(graph().unreachable(), vec![Phase::Orphan]);
+  }
+
+  #[test]
+  fn test_diagrams() {
+    assert_eq!(
+      graph().to_graphviz(),
+      "digraph Phase {\n  Start -> Playing;\n  Playing -> Over;\n  \
+       Orphan -> Over;\n}\n"
+    );
+
+    assert_eq!(
+      graph().to_mermaid(),
+      "stateDiagram-v2\n  [*] --> Start\n  Start --> Playing\n  \
+       Playing --> Over\n  Orphan --> Over\n"
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "Illegal Phase transition from Start to Over")]
+  fn test_illegal_transition_panics() {
+    let mut app: App = App::new();
+
+    app
+      .add_plugins((MinimalPlugins, StatesPlugin))
+      .init_state::<Phase>()
+      .add_plugins(graph());
+
+    app.update();
+
+    app
+      .world_mut()
+      .resource_mut::<NextState<Phase>>()
+      .set(Phase::Over);
+
+    app.update();
+  }
+}
@@ -0,0 +1,8 @@
+use ::bevy::prelude::*;
+
+/// Every run group that add_phase! registers for a phase is in this set so
+/// that other systems can be ordered against the phase's systems
+#[derive(Clone, Debug, Eq, Hash, PartialEq, SystemSet)]
+pub struct PhaseSet<T>(pub T)
+where
+  T: States;
@@ -0,0 +1,31 @@
+use ::bevy::prelude::*;
+use ::bevy::state::state::FreelyMutableState;
+
+/// Moves to `next` once the current phase has lasted as long as `timer`
+#[derive(Resource)]
+pub struct PhaseTimer<T>
+where
+  T: States,
+{
+  pub next: T,
+  pub timer: Timer,
+}
+
+pub(crate) fn remove<T>(mut commands: Commands)
+where
+  T: States,
+{
+  commands.remove_resource::<PhaseTimer<T>>();
+}
+
+pub(crate) fn tick<T>(
+  mut phase_timer: ResMut<PhaseTimer<T>>,
+  mut state: ResMut<NextState<T>>,
+  time: Res<Time>,
+) where
+  T: Copy + FreelyMutableState + States,
+{
+  if phase_timer.timer.tick(time.delta()).just_finished() {
+    state.set(phase_timer.next);
+  }
+}
@@ -6,14 +6,21 @@ use ::rand::distr::StandardUniform;
 use ::rand::distr::uniform::SampleRange;
 use ::rand::distr::uniform::SampleUniform;
 use ::rand::rngs::SysRng;
-
+use ::serde::{Deserialize, Serialize};
+
+/// The generator without the pcg or xorshift feature.  It was StdRng until
+/// games could be saved, but StdRng keeps its state private and so cannot be
+/// serialized.  Xoshiro256PlusPlus can, at the cost of a different sequence
+/// for each seed than before and of being fast rather than cryptographically
+/// secure, which is all a game's dice need.  The default pcg feature is
+/// unaffected.
 #[cfg(
   all(
     not(feature = "pcg"),
     not(feature = "xorshift")
   )
 )]
-type RngCore = ::rand::prelude::StdRng;
+type RngCore = ::rand::rngs::Xoshiro256PlusPlus;
 
 #[cfg(feature = "pcg")]
 type RngCore = ::rand_pcg::Pcg64Mcg;
@@ -21,9 +28,12 @@ type RngCore = ::rand_pcg::Pcg64Mcg;
 #[cfg(feature = "xorshift")]
 type RngCore = ::rand_xorshift::XorShiftRng;
 
-#[derive(Resource)]
+/// Serializes its whole state rather than just the seed, so a generator
+/// that is saved and loaded again carries on with the same numbers.
+#[derive(Clone, Debug, Deserialize, Resource, Serialize)]
 pub struct RandomNumberGenerator {
   rng: RngCore,
+  seed: u64,
 }
 
 impl RandomNumberGenerator {
@@ -44,18 +54,23 @@ impl RandomNumberGenerator {
     self.rng.random_range(range)
   }
 
+  pub fn seed(&self) -> u64 {
+    self.seed
+  }
+
   pub fn seeded(seed: u64) -> Self {
     Self {
       rng: RngCore::seed_from_u64(seed),
+      seed,
     }
   }
 }
 
 impl Default for RandomNumberGenerator {
   fn default() -> Self {
-    Self {
-      rng: RngCore::try_from_rng(&mut SysRng).unwrap(),
-    }
+    let seed: u64 = RngCore::try_from_rng(&mut SysRng).unwrap().random();
+
+    Self::seeded(seed)
   }
 }
 
@@ -114,4 +129,22 @@ mod test {
       );
     })
   }
+
+  #[test]
+  fn test_save_and_restore() {
+    let mut rng: RandomNumberGenerator = RandomNumberGenerator::seeded(7);
+
+    let _: u32 = rng.next();
+
+    let json: String = ::serde_json::to_string(&rng).unwrap();
+
+    let mut restored: RandomNumberGenerator =
+      ::serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.seed(), 7);
+
+    (0..100).for_each(|_| {
+      assert_eq!(rng.range(0..1_000_000), restored.range(0..1_000_000));
+    });
+  }
 }
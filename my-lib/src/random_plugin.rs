@@ -11,3 +11,17 @@ impl Plugin for RandomPlugin {
     app.insert_resource(RandomNumberGenerator::default());
   }
 }
+
+/// Gives the next game a seed of its own, drawn from the current generator
+/// so a seeded session stays reproducible.  The GameStatePlugin runs it
+/// before each game can start, so the seed a high score records is the one
+/// its game was played from.
+pub fn reseed(rng: Option<ResMut<RandomNumberGenerator>>) {
+  let Some(mut rng) = rng else {
+    return;
+  };
+
+  let seed: u64 = rng.next();
+
+  *rng = RandomNumberGenerator::seeded(seed);
+}
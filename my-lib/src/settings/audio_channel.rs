@@ -0,0 +1,48 @@
+use super::game_settings::GameSettings;
+use ::bevy::audio::Volume;
+use ::bevy::prelude::*;
+
+/// Which volume setting a sound plays at.  The SettingsPlugin keeps the
+/// volume of every playing sound with one in step with the settings, so
+/// changes on the settings screen are heard straight away.
+#[derive(Clone, Copy, Component, Debug, Eq, PartialEq)]
+pub enum AudioChannel {
+  Effects,
+  Music,
+}
+
+impl AudioChannel {
+  /// The channel's volume with the master volume applied
+  pub fn volume(
+    &self,
+    settings: &GameSettings,
+  ) -> Volume {
+    let level: f32 = match self {
+      Self::Effects => settings.effects_volume,
+      Self::Music => settings.music_volume,
+    };
+
+    Volume::Linear(level * settings.master_volume)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_volume() {
+    let settings: GameSettings = GameSettings {
+      effects_volume: 0.5,
+      master_volume: 0.5,
+      music_volume: 0.,
+      ..default()
+    };
+
+    assert_eq!(
+      AudioChannel::Effects.volume(&settings),
+      Volume::Linear(0.25)
+    );
+    assert_eq!(AudioChannel::Music.volume(&settings), Volume::Linear(0.));
+  }
+}
@@ -0,0 +1,74 @@
+use ::bevy::prelude::*;
+use ::bevy::window::{PresentMode, WindowMode, WindowResolution};
+use ::serde::{Deserialize, Serialize};
+use ::std::collections::BTreeMap;
+
+pub const RESOLUTIONS: [UVec2; 6] = [
+  UVec2::new(800, 600),
+  UVec2::new(1024, 768),
+  UVec2::new(1280, 720),
+  UVec2::new(1280, 960),
+  UVec2::new(1600, 900),
+  UVec2::new(1920, 1080),
+];
+
+pub const SETTINGS_VERSION: u32 = 1;
+
+/// User preferences.  Fields missing from an older file keep their defaults.
+#[derive(Clone, Debug, Deserialize, PartialEq, Resource, Serialize)]
+#[serde(default)]
+pub struct GameSettings {
+  pub effects_volume: f32,
+  pub fullscreen: bool,
+  /// Keys for the game's actions, keyed by action name
+  pub key_bindings: BTreeMap<String, KeyCode>,
+  pub master_volume: f32,
+  pub music_volume: f32,
+  pub resolution: UVec2,
+  pub version: u32,
+  pub vsync: bool,
+}
+
+impl GameSettings {
+  pub fn key(
+    &self,
+    action: &str,
+  ) -> Option<KeyCode> {
+    self.key_bindings.get(action).copied()
+  }
+
+  pub fn present_mode(&self) -> PresentMode {
+    if self.vsync {
+      PresentMode::AutoVsync
+    } else {
+      PresentMode::AutoNoVsync
+    }
+  }
+
+  pub fn window_mode(&self) -> WindowMode {
+    if self.fullscreen {
+      WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+    } else {
+      WindowMode::Windowed
+    }
+  }
+
+  pub fn window_resolution(&self) -> WindowResolution {
+    WindowResolution::new(self.resolution.x, self.resolution.y)
+  }
+}
+
+impl Default for GameSettings {
+  fn default() -> Self {
+    Self {
+      effects_volume: 1.,
+      fullscreen: false,
+      key_bindings: BTreeMap::new(),
+      master_volume: 1.,
+      music_volume: 1.,
+      resolution: UVec2::new(1024, 768),
+      version: SETTINGS_VERSION,
+      vsync: true,
+    }
+  }
+}
@@ -0,0 +1,10 @@
+pub mod audio_channel;
+pub mod game_settings;
+pub mod settings_cursor;
+pub mod settings_element;
+pub mod settings_item;
+pub mod settings_menu;
+pub mod settings_path;
+pub mod settings_plugin;
+pub mod settings_row;
+pub mod settings_screen;
@@ -0,0 +1,9 @@
+use ::bevy::prelude::*;
+
+/// The selected row of the settings screen
+#[derive(Default, Resource)]
+pub struct SettingsCursor {
+  /// Waiting for a key press to bind to the selected action
+  pub rebinding: bool,
+  pub selected: usize,
+}
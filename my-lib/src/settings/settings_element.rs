@@ -1,4 +1,4 @@
 use ::bevy::prelude::*;
 
 #[derive(Component)]
-pub struct DragonElement;
+pub struct SettingsElement;
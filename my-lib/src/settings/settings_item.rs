@@ -0,0 +1,161 @@
+use super::super::menu_controls;
+use super::game_settings::{GameSettings, RESOLUTIONS};
+
+const VOLUME_STEP: f32 = 0.1;
+
+/// A row of the settings screen
+#[derive(Clone, Debug, PartialEq)]
+pub enum SettingsItem {
+  EffectsVolume,
+  Fullscreen,
+  KeyBinding(String),
+  MasterVolume,
+  MusicVolume,
+  Resolution,
+  VSync,
+}
+
+impl SettingsItem {
+  /// The rows in display order with the key bindings last
+  pub fn all(settings: &GameSettings) -> Vec<Self> {
+    let mut items: Vec<Self> = vec![
+      Self::Resolution,
+      Self::Fullscreen,
+      Self::VSync,
+      Self::MasterVolume,
+      Self::MusicVolume,
+      Self::EffectsVolume,
+    ];
+
+    items.extend(settings.key_bindings.keys().cloned().map(Self::KeyBinding));
+
+    items
+  }
+
+  /// Steps the value forward or backward, wrapping choices around
+  pub fn change(
+    &self,
+    settings: &mut GameSettings,
+    forward: bool,
+  ) {
+    match self {
+      Self::EffectsVolume => step_volume(&mut settings.effects_volume, forward),
+      Self::Fullscreen => settings.fullscreen = !settings.fullscreen,
+      Self::KeyBinding(_) => {},
+      Self::MasterVolume => step_volume(&mut settings.master_volume, forward),
+      Self::MusicVolume => step_volume(&mut settings.music_volume, forward),
+      Self::Resolution => {
+        let count: usize = RESOLUTIONS.len();
+
+        let index: usize = RESOLUTIONS
+          .iter()
+          .position(|resolution| *resolution == settings.resolution)
+          .map(|index| {
+            if forward {
+              (index + 1) % count
+            } else {
+              (index + count - 1) % count
+            }
+          })
+          .unwrap_or_default();
+
+        settings.resolution = RESOLUTIONS[index];
+      },
+      Self::VSync => settings.vsync = !settings.vsync,
+    }
+  }
+
+  pub fn label(&self) -> String {
+    match self {
+      Self::EffectsVolume => "Effects volume".to_string(),
+      Self::Fullscreen => "Fullscreen".to_string(),
+      Self::KeyBinding(action) => format!("Key: {action}"),
+      Self::MasterVolume => "Master volume".to_string(),
+      Self::MusicVolume => "Music volume".to_string(),
+      Self::Resolution => "Resolution".to_string(),
+      Self::VSync => "VSync".to_string(),
+    }
+  }
+
+  pub fn value(
+    &self,
+    settings: &GameSettings,
+  ) -> String {
+    match self {
+      Self::EffectsVolume => percent(settings.effects_volume),
+      Self::Fullscreen => on_off(settings.fullscreen),
+      Self::KeyBinding(action) => settings
+        .key(action)
+        .map(menu_controls::key_label)
+        .unwrap_or_default(),
+      Self::MasterVolume => percent(settings.master_volume),
+      Self::MusicVolume => percent(settings.music_volume),
+      Self::Resolution => {
+        format!("{} x {}", settings.resolution.x, settings.resolution.y)
+      },
+      Self::VSync => on_off(settings.vsync),
+    }
+  }
+}
+
+fn on_off(value: bool) -> String {
+  if value {
+    "On".to_string()
+  } else {
+    "Off".to_string()
+  }
+}
+
+fn percent(volume: f32) -> String {
+  format!("{:.0}%", volume * 100.)
+}
+
+fn step_volume(
+  volume: &mut f32,
+  forward: bool,
+) {
+  let step: f32 = if forward {
+    VOLUME_STEP
+  } else {
+    -VOLUME_STEP
+  };
+
+  // Rounds to whole steps so that repeated steps do not drift
+  *volume =
+    ((*volume + step).clamp(0., 1.) / VOLUME_STEP).round() * VOLUME_STEP;
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_resolution_wraps() {
+    let mut settings: GameSettings = GameSettings {
+      resolution: RESOLUTIONS[0],
+      ..Default::default()
+    };
+
+    SettingsItem::Resolution.change(&mut settings, false);
+
+    assert_eq!(settings.resolution, RESOLUTIONS[RESOLUTIONS.len() - 1]);
+
+    SettingsItem::Resolution.change(&mut settings, true);
+
+    assert_eq!(settings.resolution, RESOLUTIONS[0]);
+  }
+
+  #[test]
+  fn test_volume_is_clamped() {
+    let mut settings: GameSettings = Default::default();
+
+    SettingsItem::MasterVolume.change(&mut settings, true);
+
+    assert_eq!(settings.master_volume, 1.);
+
+    (0..20)
+      .for_each(|_| SettingsItem::MasterVolume.change(&mut settings, false));
+
+    assert_eq!(settings.master_volume, 0.);
+  }
+}
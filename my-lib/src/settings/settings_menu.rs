@@ -0,0 +1,214 @@
+use super::super::persistence;
+use super::audio_channel::AudioChannel;
+use super::game_settings::GameSettings;
+use super::settings_cursor::SettingsCursor;
+use super::settings_element::SettingsElement;
+use super::settings_item::SettingsItem;
+use super::settings_path::SettingsPath;
+use super::settings_row::SettingsRow;
+use super::settings_screen::SettingsScreen;
+use ::bevy::audio::{AudioSinkPlayback, GlobalVolume, Volume};
+use ::bevy::prelude::*;
+use ::bevy::window::PrimaryWindow;
+
+const HIGHLIGHT: Color = Color::srgb(1., 0.85, 0.2);
+
+pub(crate) fn apply(
+  global_volume: Option<ResMut<GlobalVolume>>,
+  settings: Res<GameSettings>,
+  mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+  if let Ok(mut window) = window_query.single_mut() {
+    // The window was created at this size in logical pixels, so it is
+    // compared and set in logical pixels too, or a scale factor of 2 would
+    // halve it on any change to the settings
+    let resolution: Vec2 = settings.resolution.as_vec2();
+
+    if window.width() != resolution.x || window.height() != resolution.y {
+      window.resolution.set(resolution.x, resolution.y);
+    }
+
+    if window.mode != settings.window_mode() {
+      window.mode = settings.window_mode();
+    }
+
+    if window.present_mode != settings.present_mode() {
+      window.present_mode = settings.present_mode();
+    }
+  }
+
+  if let Some(mut global_volume) = global_volume {
+    global_volume.volume = Volume::Linear(settings.master_volume);
+  }
+}
+
+/// Sets the volume of each sound on a channel as it starts playing, and of
+/// them all whenever the settings change.  The master volume only reaches
+/// other sounds as they start.
+pub(crate) fn apply_volume(
+  settings: Res<GameSettings>,
+  mut sink_query: Query<(&AudioChannel, &PlaybackSettings, &mut AudioSink)>,
+) {
+  let changed: bool = settings.is_changed();
+
+  sink_query
+    .iter_mut()
+    .filter(|(.., sink)| changed || sink.is_added())
+    .for_each(|(channel, playback, mut sink)| {
+      sink.set_volume(playback.volume * channel.volume(&settings));
+    });
+}
+
+pub(crate) fn refresh(
+  cursor: Res<SettingsCursor>,
+  mut row_query: Query<(&SettingsRow, &mut Text, &mut TextColor)>,
+  settings: Res<GameSettings>,
+) {
+  let items: Vec<SettingsItem> = SettingsItem::all(&settings);
+
+  for (row, mut text, mut text_color) in row_query.iter_mut() {
+    let Some(item) = items.get(row.0) else {
+      continue;
+    };
+
+    let selected: bool = row.0 == cursor.selected;
+
+    let value: String = if selected && cursor.rebinding {
+      "press a key".to_string()
+    } else {
+      item.value(&settings)
+    };
+
+    let marker: &str = if selected {
+      ">"
+    } else {
+      " "
+    };
+
+    let line: String = format!("{marker} {}: {value}", item.label());
+
+    if text.0 != line {
+      text.0 = line;
+    }
+
+    text_color.set_if_neq(TextColor(if selected {
+      HIGHLIGHT
+    } else {
+      Color::WHITE
+    }));
+  }
+}
+
+pub(crate) fn run(
+  mut cursor: ResMut<SettingsCursor>,
+  keyboard: Res<ButtonInput<KeyCode>>,
+  mut settings: ResMut<GameSettings>,
+  mut state: ResMut<NextState<SettingsScreen>>,
+) {
+  let items: Vec<SettingsItem> = SettingsItem::all(&settings);
+
+  let Some(item) = items.get(cursor.selected) else {
+    cursor.selected = 0;
+
+    return;
+  };
+
+  if cursor.rebinding {
+    if keyboard.just_pressed(KeyCode::Escape) {
+      cursor.rebinding = false;
+    } else if let Some(key) = keyboard.get_just_pressed().next()
+      && let SettingsItem::KeyBinding(action) = item
+    {
+      settings.key_bindings.insert(action.clone(), *key);
+
+      cursor.rebinding = false;
+    }
+
+    return;
+  }
+
+  if keyboard.just_pressed(KeyCode::Escape) {
+    state.set(SettingsScreen::Closed);
+  } else if keyboard.just_pressed(KeyCode::ArrowUp) {
+    cursor.selected = (cursor.selected + items.len() - 1) % items.len();
+  } else if keyboard.just_pressed(KeyCode::ArrowDown) {
+    cursor.selected = (cursor.selected + 1) % items.len();
+  } else if keyboard.just_pressed(KeyCode::ArrowLeft) {
+    item.change(&mut settings, false);
+  } else if keyboard.just_pressed(KeyCode::ArrowRight)
+    || keyboard.just_pressed(KeyCode::Enter)
+  {
+    if let SettingsItem::KeyBinding(_) = item {
+      cursor.rebinding = true;
+    } else {
+      item.change(&mut settings, true);
+    }
+  }
+}
+
+pub(crate) fn save(
+  settings: Res<GameSettings>,
+  settings_path: Res<SettingsPath>,
+) {
+  if let Some(path) = &settings_path.0
+    && let Err(error) = persistence::save_json(&*settings, path)
+  {
+    warn!("Could not save settings: {error}");
+  }
+}
+
+pub(crate) fn setup(
+  mut commands: Commands,
+  mut cursor: ResMut<SettingsCursor>,
+  settings: Res<GameSettings>,
+) {
+  *cursor = SettingsCursor::default();
+
+  let row_count: usize = SettingsItem::all(&settings).len();
+
+  commands
+    .spawn((
+      Node {
+        align_items: AlignItems::Center,
+        flex_direction: FlexDirection::Column,
+        height: Val::Percent(100.),
+        justify_content: JustifyContent::Center,
+        position_type: PositionType::Absolute,
+        row_gap: Val::Px(8.),
+        width: Val::Percent(100.),
+        ..default()
+      },
+      BackgroundColor(Color::srgba(0., 0., 0., 0.85)),
+      GlobalZIndex(1_000),
+      SettingsElement,
+    ))
+    .with_children(|parent| {
+      parent.spawn(settings_text("Settings", 36., HIGHLIGHT));
+
+      (0..row_count).for_each(|index| {
+        parent
+          .spawn((settings_text("", 24., Color::WHITE), SettingsRow(index)));
+      });
+
+      parent.spawn(settings_text(
+        "Up/Down: select   Left/Right: change   Enter: rebind   Esc: back",
+        16.,
+        Color::WHITE,
+      ));
+    });
+}
+
+fn settings_text(
+  text: &str,
+  font_size: f32,
+  color: Color,
+) -> (Text, TextFont, TextColor) {
+  (
+    Text::new(text),
+    TextFont {
+      font_size,
+      ..default()
+    },
+    TextColor(color),
+  )
+}
@@ -0,0 +1,6 @@
+use ::bevy::prelude::*;
+use ::std::path::PathBuf;
+
+/// Where the settings are saved.  None keeps them in memory only.
+#[derive(Resource)]
+pub struct SettingsPath(pub Option<PathBuf>);
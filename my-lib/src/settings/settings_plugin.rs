@@ -0,0 +1,104 @@
+use super::super::cleanup;
+use super::super::persistence;
+use super::game_settings::{GameSettings, SETTINGS_VERSION};
+use super::settings_cursor::SettingsCursor;
+use super::settings_element::SettingsElement;
+use super::settings_menu;
+use super::settings_path::SettingsPath;
+use super::settings_screen::SettingsScreen;
+use ::bevy::prelude::*;
+use ::std::path::PathBuf;
+
+/// Inserts the GameSettings, applies changes to them live, and provides the
+/// settings screen that the GameStatePlugin main menu opens
+pub struct SettingsPlugin {
+  pub path: Option<PathBuf>,
+  pub settings: GameSettings,
+}
+
+impl SettingsPlugin {
+  /// Adds a key binding unless the saved settings already have one for the
+  /// action
+  pub fn bind<S: ToString>(
+    mut self,
+    action: S,
+    key: KeyCode,
+  ) -> Self {
+    self
+      .settings
+      .key_bindings
+      .entry(action.to_string())
+      .or_insert(key);
+
+    self
+  }
+
+  /// Loads the game's saved settings from the platform configuration
+  /// directory, falling back to the defaults
+  pub fn load<S: ToString>(game: S) -> Self {
+    let file_name: String = format!("{}-settings.json", game.to_string());
+
+    let Some(path) = persistence::config_path(&file_name) else {
+      return Self {
+        path: None,
+        settings: GameSettings::default(),
+      };
+    };
+
+    match persistence::load_json::<GameSettings>(&path) {
+      Some(settings) if settings.version > SETTINGS_VERSION => {
+        warn!("Settings will not be saved over newer {}", path.display());
+
+        Self {
+          path: None,
+          settings: GameSettings::default(),
+        }
+      },
+      Some(settings) => Self {
+        path: Some(path),
+        settings,
+      },
+      None => Self {
+        path: Some(path),
+        settings: GameSettings::default(),
+      },
+    }
+  }
+}
+
+impl Plugin for SettingsPlugin {
+  fn build(
+    &self,
+    app: &mut App,
+  ) {
+    app.insert_resource(self.settings.clone());
+
+    app.insert_resource(SettingsPath(self.path.clone()));
+
+    app.init_resource::<SettingsCursor>();
+
+    app.init_state::<SettingsScreen>();
+
+    app.add_systems(
+      Update,
+      (
+        settings_menu::apply.run_if(resource_changed::<GameSettings>),
+        settings_menu::apply_volume,
+      ),
+    );
+
+    app.add_systems(OnEnter(SettingsScreen::Open), settings_menu::setup);
+
+    app.add_systems(
+      Update,
+      (settings_menu::run, settings_menu::refresh)
+        .chain()
+        .run_if(in_state(SettingsScreen::Open)),
+    );
+
+    app.add_systems(
+      OnExit(SettingsScreen::Open),
+      (settings_menu::save, cleanup::<SettingsElement>),
+    );
+  }
+}
@@ -0,0 +1,5 @@
+use ::bevy::prelude::*;
+
+/// The text of a settings screen row by index
+#[derive(Component)]
+pub struct SettingsRow(pub usize);
@@ -0,0 +1,8 @@
+use ::bevy::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, States)]
+pub enum SettingsScreen {
+  #[default]
+  Closed,
+  Open,
+}
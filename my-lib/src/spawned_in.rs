@@ -0,0 +1,8 @@
+use ::bevy::prelude::*;
+
+/// The state a top-level entity was spawned in, as recorded by
+/// StateScopePlugin
+#[derive(Component, Debug)]
+pub struct SpawnedIn<T>(pub T)
+where
+  T: States;
@@ -0,0 +1,276 @@
+use super::spawned_in::SpawnedIn;
+use super::unscoped::Unscoped;
+use ::bevy::ecs::name::NameOrEntity;
+use ::bevy::ecs::query::Spawned;
+use ::bevy::ecs::system::SystemIdMarker;
+use ::bevy::picking::pointer::PointerId;
+use ::bevy::prelude::*;
+use ::bevy::state::state::StateTransitionSystems;
+use ::bevy::window::Monitor;
+use ::std::any::type_name;
+
+/// Top-level entities that belong to whatever state they are spawned in.
+/// The engine's own windows, monitors, pointers, gamepads, observers and
+/// one-shot systems live for the whole app, so they are left out.
+type TrackFilter = (
+  Spawned,
+  Without<ChildOf>,
+  Without<Gamepad>,
+  Without<Monitor>,
+  Without<Observer>,
+  Without<PointerId>,
+  Without<SystemIdMarker>,
+  Without<Unscoped>,
+  Without<Window>,
+);
+
+/// Despawns every visible top-level entity, with its descendants, when the
+/// state it was spawned in exits.  A group of states shares one scope, so
+/// entities live until the state leaves the group.  In debug builds a
+/// warning names every other entity that outlives its state, whether it was
+/// spawned in a kept state or has no Visibility to be scoped by.
+#[derive(Clone, Resource)]
+pub struct StateScopePlugin<T> {
+  groups: Vec<Vec<T>>,
+  kept: Vec<T>,
+}
+
+impl<T> StateScopePlugin<T>
+where
+  T: States,
+{
+  pub fn group(
+    mut self,
+    states: impl IntoIterator<Item = T>,
+  ) -> Self {
+    self.groups.push(states.into_iter().collect());
+
+    self
+  }
+
+  pub fn keep(
+    mut self,
+    state: T,
+  ) -> Self {
+    self.kept.push(state);
+
+    self
+  }
+
+  /// True if an entity spawned in `spawned` must go once `current` is entered
+  pub fn is_out_of_scope(
+    &self,
+    spawned: &T,
+    current: &T,
+  ) -> bool {
+    spawned != current
+      && !self
+        .groups
+        .iter()
+        .any(|group| group.contains(spawned) && group.contains(current))
+  }
+}
+
+impl<T> Default for StateScopePlugin<T> {
+  fn default() -> Self {
+    Self {
+      groups: Vec::new(),
+      kept: Vec::new(),
+    }
+  }
+}
+
+impl<T> Plugin for StateScopePlugin<T>
+where
+  T: States,
+{
+  fn build(
+    &self,
+    app: &mut App,
+  ) {
+    app
+      .insert_resource(self.clone())
+      .add_systems(
+        StateTransition,
+        exit::<T>.in_set(StateTransitionSystems::TransitionSchedules),
+      )
+      .add_systems(Last, track::<T>);
+  }
+}
+
+fn exit<T>(
+  mut commands: Commands,
+  state: Res<State<T>>,
+  state_scope: Res<StateScopePlugin<T>>,
+  mut transitions: MessageReader<StateTransitionEvent<T>>,
+  query: Query<(NameOrEntity, &SpawnedIn<T>, Has<Visibility>)>,
+) where
+  T: States,
+{
+  if transitions.read().last().is_none() {
+    return;
+  }
+
+  let current: &T = state.get();
+
+  query
+    .iter()
+    .filter(|(_, spawned_in, _)| {
+      state_scope.is_out_of_scope(&spawned_in.0, current)
+    })
+    .for_each(|(name, spawned_in, visible)| {
+      if visible && !state_scope.kept.contains(&spawned_in.0) {
+        commands.entity(name.entity).try_despawn();
+
+        return;
+      }
+
+      if cfg!(debug_assertions) {
+        warn!(
+          "{name} was spawned in {:?} but outlived it ({})",
+          spawned_in.0,
+          type_name::<T>(),
+        );
+      }
+
+      commands.entity(name.entity).remove::<SpawnedIn<T>>();
+    });
+}
+
+fn track<T>(
+  mut commands: Commands,
+  state: Option<Res<State<T>>>,
+  query: Query<Entity, (TrackFilter, Without<SpawnedIn<T>>)>,
+) where
+  T: States,
+{
+  let Some(state) = state else {
+    return;
+  };
+
+  query.iter().for_each(|entity| {
+    commands
+      .entity(entity)
+      .insert(SpawnedIn(state.get().clone()));
+  });
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use ::bevy::state::app::StatesPlugin;
+
+  #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, States)]
+  enum Phase {
+    #[default]
+    Menu,
+    Playing,
+    Scoring,
+    Over,
+  }
+
+  #[derive(Component)]
+  struct Marker;
+
+  fn spawn_marker(mut commands: Commands) {
+    commands.spawn((Marker, Visibility::default()));
+  }
+
+  fn markers(app: &mut App) -> usize {
+    app
+      .world_mut()
+      .query_filtered::<(), With<Marker>>()
+      .iter(app.world())
+      .count()
+  }
+
+  fn set_phase(
+    app: &mut App,
+    phase: Phase,
+  ) {
+    app
+      .world_mut()
+      .resource_mut::<NextState<Phase>>()
+      .set(phase);
+
+    app.update();
+  }
+
+  #[test]
+  fn test_groups_share_a_scope() {
+    let mut app: App = App::new();
+
+    app
+      .add_plugins((MinimalPlugins, StatesPlugin))
+      .init_state::<Phase>()
+      .add_plugins(
+        StateScopePlugin::default()
+          .group([
+            Phase::Playing,
+            Phase::Scoring,
+          ])
+          .keep(Phase::Over),
+      )
+      .add_systems(OnEnter(Phase::Playing), spawn_marker)
+      .add_systems(OnEnter(Phase::Over), spawn_marker);
+
+    set_phase(&mut app, Phase::Playing);
+    set_phase(&mut app, Phase::Scoring);
+
+    assert_eq!(markers(&mut app), 1);
+
+    set_phase(&mut app, Phase::Over);
+
+    assert_eq!(markers(&mut app), 1);
+
+    set_phase(&mut app, Phase::Menu);
+
+    assert_eq!(markers(&mut app), 1);
+  }
+
+  #[test]
+  fn test_invisible_entities_are_only_warned_about() {
+    let mut app: App = App::new();
+
+    app
+      .add_plugins((MinimalPlugins, StatesPlugin))
+      .init_state::<Phase>()
+      .add_plugins(StateScopePlugin::<Phase>::default())
+      .add_systems(OnEnter(Phase::Playing), |mut commands: Commands| {
+        commands
+          .spawn((Marker, Visibility::default()))
+          .with_child(Marker);
+        commands.spawn((Marker, Unscoped, Visibility::default()));
+        commands.spawn(Marker);
+      });
+
+    set_phase(&mut app, Phase::Playing);
+
+    assert_eq!(markers(&mut app), 4);
+
+    set_phase(&mut app, Phase::Over);
+
+    assert_eq!(markers(&mut app), 2);
+
+    let tagged: usize = app
+      .world_mut()
+      .query_filtered::<(), With<SpawnedIn<Phase>>>()
+      .iter(app.world())
+      .count();
+
+    assert_eq!(tagged, 0);
+  }
+
+  #[test]
+  fn test_is_out_of_scope() {
+    let state_scope: StateScopePlugin<Phase> = StateScopePlugin::default()
+      .group([
+        Phase::Playing,
+        Phase::Scoring,
+      ]);
+
+    assert!(!state_scope.is_out_of_scope(&Phase::Menu, &Phase::Menu));
+    assert!(!state_scope.is_out_of_scope(&Phase::Playing, &Phase::Scoring));
+    assert!(state_scope.is_out_of_scope(&Phase::Scoring, &Phase::Over));
+  }
+}
@@ -0,0 +1,299 @@
+use super::super::bevy_assets::asset_store::{AssetStore, LoadedAssets};
+use super::super::random::RandomNumberGenerator;
+use super::super::random_plugin::RandomPlugin;
+use ::bevy::app::Plugins;
+use ::bevy::asset::LoadedUntypedAsset;
+use ::bevy::ecs::query::QueryFilter;
+use ::bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+use ::bevy::input::{ButtonState, InputPlugin};
+use ::bevy::platform::collections::HashMap;
+use ::bevy::prelude::*;
+use ::bevy::state::app::StatesPlugin;
+use ::bevy::time::TimeUpdateStrategy;
+use ::std::time::Duration;
+
+/// The time that passes in each frame of a TestApp
+pub const FRAME: Duration = Duration::from_micros(16_667);
+
+/// A headless App for testing plugins and game phases without a window or
+/// GPU.  Every frame advances time by exactly one FRAME, the random number
+/// generator is seeded, and images added with `with_image` resolve through
+/// the AssetStore to placeholder images.
+pub struct TestApp {
+  pub app: App,
+}
+
+impl TestApp {
+  pub fn new(seed: u64) -> Self {
+    let mut app: App = App::new();
+
+    app
+      .add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        InputPlugin,
+        AssetPlugin::default(),
+        RandomPlugin,
+      ))
+      .init_asset::<Image>()
+      .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+      .insert_resource(RandomNumberGenerator::seeded(seed))
+      .insert_resource(AssetStore {
+        asset_index: HashMap::new(),
+      });
+
+    Self {
+      app,
+    }
+  }
+
+  /// Runs a single frame
+  pub fn advance(&mut self) -> &mut Self {
+    self.app.update();
+
+    self
+  }
+
+  pub fn advance_frames(
+    &mut self,
+    frames: usize,
+  ) -> &mut Self {
+    (0..frames).for_each(|_| self.app.update());
+
+    self
+  }
+
+  /// Runs as many frames as it takes for `duration` to pass, so FixedUpdate
+  /// runs as often as it would in real time
+  pub fn advance_time(
+    &mut self,
+    duration: Duration,
+  ) -> &mut Self {
+    let frames: usize = duration.as_nanos().div_ceil(FRAME.as_nanos()) as usize;
+
+    self.advance_frames(frames)
+  }
+
+  /// The number of entities that match the filter
+  pub fn count<F>(&mut self) -> usize
+  where
+    F: QueryFilter,
+  {
+    let world: &mut World = self.app.world_mut();
+
+    world.query_filtered::<(), F>().iter(world).count()
+  }
+
+  /// Holds a key down from the next frame on
+  pub fn press(
+    &mut self,
+    key: KeyCode,
+  ) -> &mut Self {
+    self.send_key(key, ButtonState::Pressed)
+  }
+
+  /// A copy of each component of type C
+  pub fn query<C>(&mut self) -> Vec<C>
+  where
+    C: Clone + Component,
+  {
+    let world: &mut World = self.app.world_mut();
+
+    world.query::<&C>().iter(world).cloned().collect()
+  }
+
+  pub fn release(
+    &mut self,
+    key: KeyCode,
+  ) -> &mut Self {
+    self.send_key(key, ButtonState::Released)
+  }
+
+  pub fn resource<R>(&self) -> &R
+  where
+    R: Resource,
+  {
+    self.app.world().resource::<R>()
+  }
+
+  pub fn set_state<S>(
+    &mut self,
+    state: S,
+  ) -> &mut Self
+  where
+    S: ::bevy::state::state::FreelyMutableState,
+  {
+    self
+      .app
+      .world_mut()
+      .resource_mut::<NextState<S>>()
+      .set(state);
+
+    self
+  }
+
+  pub fn state<S>(&self) -> S
+  where
+    S: States,
+  {
+    self.app.world().resource::<State<S>>().get().clone()
+  }
+
+  /// Presses a key for one frame and then releases it
+  pub fn tap(
+    &mut self,
+    key: KeyCode,
+  ) -> &mut Self {
+    self.press(key).advance().release(key).advance()
+  }
+
+  /// Registers a placeholder image under `tag` in the AssetStore
+  pub fn with_image<S: ToString>(
+    mut self,
+    tag: S,
+  ) -> Self {
+    let world: &mut World = self.app.world_mut();
+
+    let image: Handle<Image> =
+      world.resource_mut::<Assets<Image>>().add(Image::default());
+
+    let loaded: Handle<LoadedUntypedAsset> = world
+      .resource_mut::<LoadedAssets>()
+      .add(LoadedUntypedAsset {
+        handle: image.untyped(),
+      });
+
+    world
+      .resource_mut::<AssetStore>()
+      .asset_index
+      .insert(tag.to_string(), loaded);
+
+    self
+  }
+
+  pub fn with_plugins<M>(
+    mut self,
+    plugins: impl Plugins<M>,
+  ) -> Self {
+    self.app.add_plugins(plugins);
+
+    self
+  }
+
+  fn send_key(
+    &mut self,
+    key: KeyCode,
+    state: ButtonState,
+  ) -> &mut Self {
+    self.app.world_mut().write_message(KeyboardInput {
+      key_code: key,
+      logical_key: Key::Unidentified(NativeKey::Unidentified),
+      repeat: false,
+      state,
+      text: None,
+      window: Entity::PLACEHOLDER,
+    });
+
+    self
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::super::super::game_state_plugin::GameStatePlugin;
+  use super::super::super::menu_controls::MenuControls;
+  use super::super::super::menu_element::MenuElement;
+  use super::super::super::pause::pause_state::PauseState;
+  use super::*;
+
+  #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, States)]
+  enum Phase {
+    Over,
+    #[default]
+    Loading,
+    Menu,
+    Playing,
+  }
+
+  fn test_app() -> TestApp {
+    TestApp::new(7).with_plugins(GameStatePlugin {
+      game_end_state: Phase::Over,
+      game_start_state: Phase::Playing,
+      menu_controls: MenuControls::default(),
+      menu_state: Phase::Menu,
+    })
+  }
+
+  #[test]
+  fn test_menu_flow() {
+    let mut test_app: TestApp = test_app();
+
+    test_app.advance_frames(2);
+
+    assert_eq!(test_app.state::<Phase>(), Phase::Menu);
+    assert!(test_app.count::<With<MenuElement>>() > 0);
+
+    test_app.tap(KeyCode::KeyP);
+
+    assert_eq!(test_app.state::<Phase>(), Phase::Playing);
+    assert_eq!(test_app.count::<With<MenuElement>>(), 0);
+
+    test_app.tap(KeyCode::Escape);
+
+    assert_eq!(test_app.state::<PauseState>(), PauseState::Paused);
+
+    test_app.tap(KeyCode::KeyM).advance();
+
+    assert_eq!(test_app.state::<Phase>(), Phase::Menu);
+    assert_eq!(test_app.state::<PauseState>(), PauseState::Running);
+  }
+
+  #[test]
+  fn test_each_game_has_its_own_seed() {
+    let mut game: TestApp = test_app();
+
+    game.advance_frames(2);
+
+    let first: u64 = game.resource::<RandomNumberGenerator>().seed();
+
+    assert_ne!(first, 7);
+
+    game.tap(KeyCode::KeyP);
+
+    assert_eq!(game.state::<Phase>(), Phase::Playing);
+    assert_eq!(game.resource::<RandomNumberGenerator>().seed(), first);
+
+    game.set_state(Phase::Over).advance();
+
+    let second: u64 = game.resource::<RandomNumberGenerator>().seed();
+
+    assert_ne!(second, first);
+
+    let mut again: TestApp = test_app();
+
+    again.advance_frames(2);
+
+    assert_eq!(again.resource::<RandomNumberGenerator>().seed(), first);
+  }
+
+  #[test]
+  fn test_time_and_images() {
+    let mut test_app: TestApp = test_app().with_image("wall");
+
+    let handle: Option<Handle<Image>> = {
+      let world: &World = test_app.app.world();
+
+      world
+        .resource::<AssetStore>()
+        .get_handle("wall", world.resource::<LoadedAssets>())
+    };
+
+    assert!(handle.is_some());
+
+    test_app.advance_time(Duration::from_secs(1));
+
+    let elapsed: Duration = test_app.resource::<Time>().elapsed();
+
+    assert!(elapsed >= Duration::from_millis(980));
+  }
+}
@@ -0,0 +1,20 @@
+use super::transition::Transition;
+use ::bevy::prelude::*;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransitionPhase {
+  /// Waiting for a snapshot of the outgoing screen
+  Capturing,
+  /// Hiding the outgoing state before the state change
+  Covering,
+  /// Showing the incoming state after the state change
+  Revealing,
+}
+
+pub struct ActiveTransition<T> {
+  pub phase: TransitionPhase,
+  pub(crate) snapshot: Option<Handle<Image>>,
+  pub target: T,
+  pub(crate) timer: Timer,
+  pub transition: Transition,
+}
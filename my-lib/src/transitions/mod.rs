@@ -0,0 +1,7 @@
+pub mod active_transition;
+pub mod transition;
+pub mod transition_effect;
+pub mod transition_overlay;
+pub mod transition_plugin;
+pub mod transition_resource;
+pub mod transition_runner;
@@ -0,0 +1,38 @@
+use super::transition_effect::TransitionEffect;
+use ::bevy::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Transition {
+  /// Total time in seconds including both the outgoing and incoming halves
+  pub duration: f32,
+  pub effect: TransitionEffect,
+}
+
+impl Transition {
+  pub fn cross_fade(duration: f32) -> Self {
+    Self {
+      duration,
+      effect: TransitionEffect::CrossFade,
+    }
+  }
+
+  pub fn fade(
+    color: Color,
+    duration: f32,
+  ) -> Self {
+    Self {
+      duration,
+      effect: TransitionEffect::Fade(color),
+    }
+  }
+
+  pub fn wipe(
+    color: Color,
+    duration: f32,
+  ) -> Self {
+    Self {
+      duration,
+      effect: TransitionEffect::Wipe(color),
+    }
+  }
+}
@@ -0,0 +1,11 @@
+use ::bevy::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub enum TransitionEffect {
+  /// Blends a snapshot of the outgoing screen into the incoming screen
+  CrossFade,
+  /// Fades out to the color and then fades in from it
+  Fade(Color),
+  /// Sweeps the color across the screen from left to right
+  Wipe(Color),
+}
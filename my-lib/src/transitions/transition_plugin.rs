@@ -0,0 +1,119 @@
+use super::transition::Transition;
+use super::transition_resource::TransitionResource;
+use super::transition_runner;
+use ::bevy::input::InputSystems;
+use ::bevy::platform::collections::HashMap;
+use ::bevy::prelude::*;
+use ::bevy::state::state::FreelyMutableState;
+
+/// Runs the configured transition whenever a state with a transition is
+/// entered or exited.  When both states have one, the entered state wins.
+#[derive(Clone)]
+pub struct TransitionPlugin<T> {
+  transitions: HashMap<T, Transition>,
+}
+
+impl<T> TransitionPlugin<T>
+where
+  T: States,
+{
+  pub fn add_state(
+    mut self,
+    state: T,
+    transition: Transition,
+  ) -> Self {
+    self.transitions.insert(state, transition);
+
+    self
+  }
+}
+
+impl<T> Default for TransitionPlugin<T> {
+  fn default() -> Self {
+    Self {
+      transitions: HashMap::new(),
+    }
+  }
+}
+
+impl<T> Plugin for TransitionPlugin<T>
+where
+  T: Copy + FreelyMutableState + States,
+{
+  fn build(
+    &self,
+    app: &mut App,
+  ) {
+    app.insert_resource(TransitionResource {
+      active: None,
+      transitions: self.transitions.clone(),
+    });
+
+    app.add_systems(
+      PreUpdate,
+      (
+        transition_runner::block_input
+          .run_if(transition_runner::is_transitioning::<T>),
+        transition_runner::intercept::<T>,
+        transition_runner::run::<T>,
+      )
+        .chain()
+        .after(InputSystems),
+    );
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::super::super::game_state_plugin::GameStatePlugin;
+  use super::super::super::menu_controls::MenuControls;
+  use super::super::super::pause::pause_state::PauseState;
+  use super::super::super::testing::test_app::TestApp;
+  use super::*;
+  use ::std::time::Duration;
+
+  #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, States)]
+  enum Phase {
+    Over,
+    #[default]
+    Loading,
+    Menu,
+    Playing,
+  }
+
+  #[test]
+  fn test_menu_from_pause() {
+    let mut test_app: TestApp = TestApp::new(3)
+      .with_plugins(GameStatePlugin {
+        game_end_state: Phase::Over,
+        game_start_state: Phase::Playing,
+        menu_controls: MenuControls::default(),
+        menu_state: Phase::Menu,
+      })
+      .with_plugins(
+        TransitionPlugin::default()
+          .add_state(Phase::Menu, Transition::fade(Color::BLACK, 0.2)),
+      );
+
+    test_app.advance_time(Duration::from_secs(1));
+
+    assert_eq!(test_app.state::<Phase>(), Phase::Menu);
+
+    test_app
+      .tap(KeyCode::KeyP)
+      .advance_time(Duration::from_secs(1));
+
+    assert_eq!(test_app.state::<Phase>(), Phase::Playing);
+
+    test_app.tap(KeyCode::Escape);
+
+    assert_eq!(test_app.state::<PauseState>(), PauseState::Paused);
+
+    test_app
+      .tap(KeyCode::KeyM)
+      .advance_time(Duration::from_secs(1));
+
+    assert_eq!(test_app.state::<Phase>(), Phase::Menu);
+    assert_eq!(test_app.state::<PauseState>(), PauseState::Running);
+  }
+}
@@ -0,0 +1,10 @@
+use super::active_transition::ActiveTransition;
+use super::transition::Transition;
+use ::bevy::platform::collections::HashMap;
+use ::bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct TransitionResource<T> {
+  pub active: Option<ActiveTransition<T>>,
+  pub(crate) transitions: HashMap<T, Transition>,
+}
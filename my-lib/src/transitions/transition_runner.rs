@@ -0,0 +1,274 @@
+use super::super::unscoped::Unscoped;
+use super::active_transition::{ActiveTransition, TransitionPhase};
+use super::transition::Transition;
+use super::transition_effect::TransitionEffect;
+use super::transition_overlay::TransitionOverlay;
+use super::transition_resource::TransitionResource;
+use ::bevy::prelude::*;
+use ::bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use ::bevy::state::state::FreelyMutableState;
+use ::bevy::ui::FocusPolicy;
+
+type OverlayQuery<'w, 's> = Query<
+  'w,
+  's,
+  (
+    Entity,
+    &'static mut Node,
+    Option<&'static mut BackgroundColor>,
+    Option<&'static mut ImageNode>,
+  ),
+  With<TransitionOverlay>,
+>;
+
+/// Run condition that is true while a transition is on screen
+pub fn is_transitioning<T>(
+  transition_resource: Option<Res<TransitionResource<T>>>
+) -> bool
+where
+  T: States,
+{
+  transition_resource.is_some_and(|resource| resource.active.is_some())
+}
+
+pub(crate) fn block_input(
+  keyboard: Option<ResMut<ButtonInput<KeyCode>>>,
+  mouse: Option<ResMut<ButtonInput<MouseButton>>>,
+) {
+  if let Some(mut keyboard) = keyboard {
+    keyboard.reset_all();
+  }
+
+  if let Some(mut mouse) = mouse {
+    mouse.reset_all();
+  }
+}
+
+/// Holds back a pending state change that has a transition configured
+pub(crate) fn intercept<T>(
+  mut commands: Commands,
+  current_state: Res<State<T>>,
+  mut next_state: ResMut<NextState<T>>,
+  mut transition_resource: ResMut<TransitionResource<T>>,
+) where
+  T: Copy + FreelyMutableState + States,
+{
+  let target: T = match &*next_state {
+    NextState::Unchanged => return,
+    NextState::Pending(target) | NextState::PendingIfNeq(target) => *target,
+  };
+
+  if transition_resource.active.is_some() {
+    // Only the transition itself may change the state until it finishes
+    next_state.reset();
+
+    return;
+  }
+
+  let current: T = *current_state.get();
+
+  if target == current {
+    return;
+  }
+
+  let Some(transition) = transition_resource
+    .transitions
+    .get(&target)
+    .or_else(|| transition_resource.transitions.get(&current))
+    .copied()
+  else {
+    return;
+  };
+
+  next_state.reset();
+
+  let phase: TransitionPhase = match transition.effect {
+    TransitionEffect::CrossFade => {
+      commands
+        .spawn((Screenshot::primary_window(), Unscoped))
+        .observe(capture::<T>);
+
+      TransitionPhase::Capturing
+    },
+    TransitionEffect::Fade(color) => {
+      spawn_overlay(&mut commands, color.with_alpha(0.), 100.);
+
+      TransitionPhase::Covering
+    },
+    TransitionEffect::Wipe(color) => {
+      spawn_overlay(&mut commands, color, 0.);
+
+      TransitionPhase::Covering
+    },
+  };
+
+  let seconds: f32 = match phase {
+    TransitionPhase::Capturing => transition.duration,
+    _ => transition.duration / 2.,
+  };
+
+  transition_resource.active = Some(ActiveTransition {
+    phase,
+    snapshot: None,
+    target,
+    timer: Timer::from_seconds(seconds, TimerMode::Once),
+    transition,
+  });
+}
+
+pub(crate) fn run<T>(
+  mut commands: Commands,
+  mut next_state: ResMut<NextState<T>>,
+  mut overlay_query: OverlayQuery,
+  mut transition_resource: ResMut<TransitionResource<T>>,
+  time: Res<Time<Real>>,
+) where
+  T: Copy + FreelyMutableState + States,
+{
+  let Some(active) = transition_resource.active.as_mut() else {
+    return;
+  };
+
+  // Real time, because leaving a paused game runs its transition while the
+  // game's own clock is stopped
+
+  active.timer.tick(time.delta());
+
+  match active.phase {
+    TransitionPhase::Capturing => {
+      // Without a snapshot by the deadline the state changes without one
+      if let Some(snapshot) = active.snapshot.take() {
+        commands.spawn((
+          overlay_node(100.),
+          ImageNode::new(snapshot),
+          FocusPolicy::Block,
+          GlobalZIndex(i32::MAX),
+          TransitionOverlay,
+          Unscoped,
+        ));
+      } else if !active.timer.is_finished() {
+        return;
+      }
+
+      reveal(active, &mut next_state);
+    },
+    TransitionPhase::Covering => {
+      update_overlays(active, &mut overlay_query);
+
+      if active.timer.is_finished() {
+        reveal(active, &mut next_state);
+      }
+    },
+    TransitionPhase::Revealing => {
+      update_overlays(active, &mut overlay_query);
+
+      if active.timer.is_finished() {
+        overlay_query
+          .iter()
+          .for_each(|(entity, ..)| commands.entity(entity).despawn());
+
+        transition_resource.active = None;
+      }
+    },
+  }
+}
+
+fn capture<T>(
+  captured: On<ScreenshotCaptured>,
+  mut images: ResMut<Assets<Image>>,
+  mut transition_resource: ResMut<TransitionResource<T>>,
+) where
+  T: States,
+{
+  if let Some(active) = transition_resource.active.as_mut()
+    && active.phase == TransitionPhase::Capturing
+  {
+    active.snapshot = Some(images.add(captured.image.clone()));
+  }
+}
+
+fn overlay_node(width: f32) -> Node {
+  Node {
+    height: Val::Percent(100.),
+    left: Val::Percent(0.),
+    position_type: PositionType::Absolute,
+    top: Val::Percent(0.),
+    width: Val::Percent(width),
+    ..default()
+  }
+}
+
+/// Changes the state while the screen is covered
+fn reveal<T>(
+  active: &mut ActiveTransition<T>,
+  next_state: &mut NextState<T>,
+) where
+  T: Copy + FreelyMutableState + States,
+{
+  next_state.set(active.target);
+
+  let transition: Transition = active.transition;
+
+  let seconds: f32 = match transition.effect {
+    TransitionEffect::CrossFade => transition.duration,
+    _ => transition.duration / 2.,
+  };
+
+  active.phase = TransitionPhase::Revealing;
+
+  active.timer = Timer::from_seconds(seconds, TimerMode::Once);
+}
+
+fn spawn_overlay(
+  commands: &mut Commands,
+  color: Color,
+  width: f32,
+) {
+  commands.spawn((
+    overlay_node(width),
+    BackgroundColor(color),
+    FocusPolicy::Block,
+    GlobalZIndex(i32::MAX),
+    TransitionOverlay,
+    Unscoped,
+  ));
+}
+
+fn update_overlays<T>(
+  active: &ActiveTransition<T>,
+  overlay_query: &mut OverlayQuery,
+) {
+  let progress: f32 = active.timer.fraction();
+
+  let covering: bool = active.phase == TransitionPhase::Covering;
+
+  for (_, mut node, background, image) in overlay_query.iter_mut() {
+    match active.transition.effect {
+      TransitionEffect::CrossFade => {
+        if let Some(mut image) = image {
+          image.color = Color::WHITE.with_alpha(1. - progress);
+        }
+      },
+      TransitionEffect::Fade(color) => {
+        let alpha: f32 = if covering {
+          progress
+        } else {
+          1. - progress
+        };
+
+        if let Some(mut background) = background {
+          background.0 = color.with_alpha(alpha);
+        }
+      },
+      TransitionEffect::Wipe(_) => {
+        if covering {
+          node.width = Val::Percent(progress * 100.);
+        } else {
+          node.left = Val::Percent(progress * 100.);
+
+          node.width = Val::Percent((1. - progress) * 100.);
+        }
+      },
+    }
+  }
+}
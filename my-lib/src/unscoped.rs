@@ -0,0 +1,6 @@
+use ::bevy::prelude::*;
+
+/// Keeps a top-level entity alive across state changes, and out of the
+/// StateScopePlugin's leak warnings, when it is meant to outlive its state
+#[derive(Component)]
+pub struct Unscoped;
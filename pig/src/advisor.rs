@@ -0,0 +1,44 @@
+use ::bevy::prelude::*;
+use ::bevy::tasks::{AsyncComputeTaskPool, Task, block_on, poll_once};
+use ::pig::strategies::optimal::Optimal;
+
+/// The optimal policy behind the hints overlay, solved in the background
+/// for the target being played to
+#[derive(Default, Resource)]
+pub struct Advisor {
+  pub optimal: Option<Optimal>,
+  pub pending: Option<Task<Optimal>>,
+  pub target: Option<usize>,
+}
+
+impl Advisor {
+  /// Starts solving for the target unless that is already done or under way
+  pub fn prepare(
+    &mut self,
+    target: usize,
+  ) {
+    if self.target == Some(target) {
+      return;
+    }
+
+    self.target = Some(target);
+
+    self.optimal = None;
+
+    self.pending = Some(
+      AsyncComputeTaskPool::get().spawn(async move { Optimal::new(target) }),
+    );
+  }
+}
+
+pub fn poll(mut advisor: ResMut<Advisor>) {
+  let Some(task) = &mut advisor.pending else {
+    return;
+  };
+
+  if let Some(optimal) = block_on(poll_once(task)) {
+    advisor.optimal = Some(optimal);
+
+    advisor.pending = None;
+  }
+}
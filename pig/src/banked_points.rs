@@ -0,0 +1,37 @@
+use ::bevy::prelude::*;
+
+/// Text showing a banked hand, which flies from the dice to the scoreboard
+/// and fades out on the way
+#[derive(Component)]
+pub struct BankedPoints {
+  pub from: Vec3,
+  pub timer: Timer,
+  pub to: Vec3,
+}
+
+pub fn animate(
+  mut commands: Commands,
+  mut points_query: Query<(
+    Entity,
+    &mut BankedPoints,
+    &mut TextColor,
+    &mut Transform,
+  )>,
+  time: Res<Time>,
+) {
+  points_query.iter_mut().for_each(
+    |(entity, mut points, mut text_color, mut transform)| {
+      points.timer.tick(time.delta());
+
+      let progress: f32 = points.timer.fraction();
+
+      transform.translation = points.from.lerp(points.to, progress);
+
+      text_color.0.set_alpha(1. - progress * progress);
+
+      if points.timer.is_finished() {
+        commands.entity(entity).despawn();
+      }
+    },
+  );
+}
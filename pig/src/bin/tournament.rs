@@ -0,0 +1,156 @@
+//! Plays seeded games of Pig between CPU strategies and reports how they
+//! fare against each other.
+//!
+//! cargo run --release -p pig --bin tournament -- [--games N] [--seed N]
+//!   [--threads N] [--target N] [--variant NAME] [--finish-round] [--csv]
+//!   [strategy ...]
+
+use ::pig::model::rules::Rules;
+use ::pig::strategies::strategy::Strategy;
+use ::pig::strategies::strategy_kind::StrategyKind;
+use ::pig::tournament::match_result::MatchResult;
+use ::pig::tournament::simulator;
+use ::std::env;
+use ::std::thread;
+
+struct Options {
+  csv: bool,
+  games: usize,
+  kinds: Vec<StrategyKind>,
+  rules: Rules,
+  seed: u64,
+  threads: usize,
+}
+
+fn main() -> ::anyhow::Result<()> {
+  let options: Options = parse(env::args().skip(1))?;
+
+  let strategies: Vec<(StrategyKind, Box<dyn Strategy>)> = options
+    .kinds
+    .iter()
+    .map(|kind| (*kind, kind.build(options.rules.target)))
+    .collect();
+
+  if options.csv {
+    println!("a,b,games,a_win_rate,a_win_ci,average_turns,first_win_rate");
+  } else {
+    println!(
+      "{:<12} {:<12} {:>9} {:>16} {:>9} {:>16}",
+      "A", "B", "Games", "A wins", "Turns", "First wins"
+    );
+  }
+
+  for (index, (a_kind, a)) in strategies.iter().enumerate() {
+    for (b_kind, b) in strategies.iter().skip(index + 1) {
+      let result: MatchResult = simulator::play_match(
+        a.as_ref(),
+        b.as_ref(),
+        options.rules,
+        options.games,
+        options.seed,
+        options.threads,
+      );
+
+      report(&options, *a_kind, *b_kind, &result);
+    }
+  }
+
+  Ok(())
+}
+
+fn parse(args: impl Iterator<Item = String>) -> ::anyhow::Result<Options> {
+  let mut options: Options = Options {
+    csv: false,
+    games: 1_000_000,
+    kinds: Vec::new(),
+    rules: Rules::default(),
+    seed: 1,
+    threads: thread::available_parallelism().map_or(1, |count| count.get()),
+  };
+
+  let mut args = args;
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--csv" => options.csv = true,
+      "--finish-round" => options.rules.finish_round = true,
+      "--games" => options.games = value(&mut args, &arg)?,
+      "--seed" => options.seed = value(&mut args, &arg)?,
+      "--target" => options.rules.target = value(&mut args, &arg)?,
+      "--threads" => options.threads = value(&mut args, &arg)?,
+      "--variant" => {
+        options.rules.variant = args
+          .next()
+          .unwrap_or_default()
+          .parse()
+          .map_err(::anyhow::Error::msg)?;
+      },
+      name => options
+        .kinds
+        .push(name.parse().map_err(::anyhow::Error::msg)?),
+    }
+  }
+
+  if options.kinds.is_empty() {
+    options.kinds = StrategyKind::all().to_vec();
+  }
+
+  if options.rules.target == 0 {
+    ::anyhow::bail!("The target must be at least 1");
+  }
+
+  if options.kinds.len() < 2 {
+    ::anyhow::bail!("A tournament needs at least two strategies");
+  }
+
+  Ok(options)
+}
+
+fn report(
+  options: &Options,
+  a: StrategyKind,
+  b: StrategyKind,
+  result: &MatchResult,
+) {
+  let a_win_rate: f64 = result.a_win_rate();
+
+  let first_win_rate: f64 = result.first_player_win_rate();
+
+  if options.csv {
+    println!(
+      "{},{},{},{:.5},{:.5},{:.3},{:.5}",
+      a.name(),
+      b.name(),
+      result.games,
+      a_win_rate,
+      result.confidence(a_win_rate),
+      result.average_turns(),
+      first_win_rate,
+    );
+  } else {
+    println!(
+      "{:<12} {:<12} {:>9} {:>7.2}% ±{:>5.2}% {:>9.1} {:>7.2}% ±{:>5.2}%",
+      a.name(),
+      b.name(),
+      result.games,
+      a_win_rate * 100.,
+      result.confidence(a_win_rate) * 100.,
+      result.average_turns(),
+      first_win_rate * 100.,
+      result.confidence(first_win_rate) * 100.,
+    );
+  }
+}
+
+fn value<T>(
+  args: &mut impl Iterator<Item = String>,
+  flag: &str,
+) -> ::anyhow::Result<T>
+where
+  T: ::std::str::FromStr,
+{
+  args
+    .next()
+    .and_then(|value| value.parse().ok())
+    .ok_or_else(|| ::anyhow::anyhow!("{flag} needs a number"))
+}
@@ -0,0 +1,25 @@
+use super::hand_die;
+use ::bevy::prelude::*;
+
+/// The message shown when a roll loses the hand.  It is spawned hidden with
+/// the dice and shown for the length of its timer once they settle.
+#[derive(Component)]
+pub struct BustNotice(pub Timer);
+
+/// Hidden until the dice settle
+pub fn spawn(
+  commands: &mut Commands,
+  message: &str,
+) {
+  commands.spawn((
+    BustNotice(Timer::from_seconds(1.2, TimerMode::Once)),
+    Text2d::new(message),
+    TextColor(Color::srgb(1., 0.3, 0.3)),
+    TextFont {
+      font_size: 72.,
+      ..default()
+    },
+    Transform::from_xyz(0., hand_die::DICE_TOP + 120., 2.),
+    Visibility::Hidden,
+  ));
+}
@@ -0,0 +1,42 @@
+use ::bevy::prelude::*;
+
+/// How long the CPU waits before each roll or hold, as chosen on the main
+/// menu, from slowest to fastest
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Resource)]
+pub enum CpuPace {
+  Slow,
+  #[default]
+  Normal,
+  Fast,
+  /// Plays the whole turn at once, as if it were fast-forwarded
+  Instant,
+}
+
+impl CpuPace {
+  pub fn all() -> [Self; 4] {
+    [
+      Self::Slow,
+      Self::Normal,
+      Self::Fast,
+      Self::Instant,
+    ]
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      Self::Fast => "Fast",
+      Self::Instant => "Instant",
+      Self::Normal => "Normal",
+      Self::Slow => "Slow",
+    }
+  }
+
+  pub fn seconds(&self) -> f32 {
+    match self {
+      Self::Fast => 0.2,
+      Self::Instant => 0.,
+      Self::Normal => 0.5,
+      Self::Slow => 1.,
+    }
+  }
+}
@@ -0,0 +1,59 @@
+use ::bevy::prelude::*;
+use ::bevy::tasks::{AsyncComputeTaskPool, Task, block_on, poll_once};
+use ::pig::model::pig_game::DEFAULT_TARGET;
+use ::pig::strategies::strategy::Strategy;
+use ::pig::strategies::strategy_kind::StrategyKind;
+
+/// How the CPU plays, as chosen on the main menu.  Some strategies take a
+/// while to build, so the previous one stays in play until the new one is
+/// ready.  Strategies are built for a target, so changing the target
+/// rebuilds the strategy too.
+#[derive(Resource)]
+pub struct CpuStrategy {
+  pub kind: StrategyKind,
+  pub pending: Option<Task<Box<dyn Strategy>>>,
+  pub strategy: Box<dyn Strategy>,
+  pub target: usize,
+}
+
+impl CpuStrategy {
+  /// Starts building the strategy in the background
+  pub fn choose(
+    &mut self,
+    kind: StrategyKind,
+    target: usize,
+  ) {
+    self.kind = kind;
+
+    self.target = target;
+
+    self.pending = Some(
+      AsyncComputeTaskPool::get().spawn(async move { kind.build(target) }),
+    );
+  }
+}
+
+impl Default for CpuStrategy {
+  fn default() -> Self {
+    let kind: StrategyKind = StrategyKind::default();
+
+    Self {
+      kind,
+      pending: None,
+      strategy: kind.build(DEFAULT_TARGET),
+      target: DEFAULT_TARGET,
+    }
+  }
+}
+
+pub fn poll(mut cpu_strategy: ResMut<CpuStrategy>) {
+  let Some(task) = &mut cpu_strategy.pending else {
+    return;
+  };
+
+  if let Some(strategy) = block_on(poll_once(task)) {
+    cpu_strategy.strategy = strategy;
+
+    cpu_strategy.pending = None;
+  }
+}
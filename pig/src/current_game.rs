@@ -0,0 +1,6 @@
+use ::bevy::prelude::*;
+use ::pig::model::pig_game::PigGame;
+
+/// The game being played, which the systems show and take input for
+#[derive(Deref, DerefMut, Resource)]
+pub struct CurrentGame(pub PigGame);
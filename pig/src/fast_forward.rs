@@ -0,0 +1,6 @@
+use ::bevy::prelude::*;
+
+/// True while the current CPU turn is played without waiting, either
+/// because the player asked to skip ahead or the pace is instant
+#[derive(Default, Resource)]
+pub struct FastForward(pub bool);
@@ -0,0 +1,74 @@
+use super::roster::Roster;
+use ::my_lib::persistence;
+use ::pig::model::pig_game::PigGame;
+use ::pig::model::turn_record::TurnRecord;
+use ::serde::Serialize;
+use ::std::fs;
+use ::std::path::Path;
+
+/// A finished game's turn history with the players' names, as exported from
+/// the game log panel
+#[derive(Serialize)]
+pub struct GameLogFile<'a> {
+  pub players: Vec<&'a str>,
+  pub scores: &'a [usize],
+  pub turns: &'a [TurnRecord],
+  pub winner: Option<&'a str>,
+}
+
+impl<'a> GameLogFile<'a> {
+  pub fn new(
+    game: &'a PigGame,
+    roster: &'a Roster,
+  ) -> Self {
+    let players: Vec<&str> = roster
+      .players
+      .iter()
+      .map(|entry| entry.name.as_str())
+      .collect();
+
+    let winner: Option<&str> = game.winner().map(|winner| players[winner]);
+
+    Self {
+      players,
+      scores: game.scores(),
+      turns: game.history(),
+      winner,
+    }
+  }
+
+  /// One line per turn
+  pub fn lines(&self) -> Vec<String> {
+    self
+      .turns
+      .iter()
+      .map(|turn| turn.describe(self.players[turn.player]))
+      .collect()
+  }
+
+  pub fn save_json(
+    &self,
+    path: &Path,
+  ) -> ::anyhow::Result<()> {
+    persistence::save_json(self, path)
+  }
+
+  pub fn save_text(
+    &self,
+    path: &Path,
+  ) -> ::anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    let mut text: String = self.lines().join("\n");
+
+    if let Some(winner) = self.winner {
+      text.push_str(&format!("\n{winner} wins\n"));
+    }
+
+    fs::write(path, text)?;
+
+    Ok(())
+  }
+}
@@ -1,12 +1,23 @@
 use ::bevy::prelude::*;
+use ::my_lib::phase_graph::PhaseGraph;
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, States)]
 pub enum GamePhase {
-  Cpu,
-  End,
   GameOver,
-  Player,
-  MainMenu,
   #[default]
-  Start,
+  Loading,
+  MainMenu,
+  Playing,
+}
+
+impl GamePhase {
+  pub fn graph() -> PhaseGraph<Self> {
+    PhaseGraph::default()
+      .allow(Self::Loading, Self::MainMenu)
+      .allow(Self::MainMenu, Self::Playing)
+      .allow(Self::Playing, Self::GameOver)
+      .allow(Self::Playing, Self::MainMenu)
+      .allow(Self::GameOver, Self::MainMenu)
+      .allow(Self::GameOver, Self::Playing)
+  }
 }
@@ -1,4 +1,62 @@
+use super::game_assets::GameAssets;
+use super::tumble::Tumble;
 use ::bevy::prelude::*;
 
+const DICE_PER_ROW: usize = 8;
+
+/// Where the top row of dice sits, which the messages about the hand are
+/// placed relative to
+pub const DICE_TOP: f32 = 200.;
+
+const DIE_SIZE: f32 = 128.;
+
+const DIE_SPACING: f32 = 140.;
+
 #[derive(Component)]
 pub struct HandDie;
+
+pub fn clear(
+  hand_query: &Query<Entity, With<HandDie>>,
+  commands: &mut Commands,
+) {
+  hand_query
+    .iter()
+    .for_each(|entity| commands.entity(entity).despawn());
+}
+
+pub fn spawn(
+  commands: &mut Commands,
+  assets: &GameAssets,
+  position: usize,
+  new_roll: usize,
+  color: Color,
+  seconds: f32,
+) {
+  // Rows of small dice leave room for the several dice a roll can add
+  let column: f32 = (position % DICE_PER_ROW) as f32;
+
+  let row: f32 = (position / DICE_PER_ROW) as f32;
+
+  let mut sprite: Sprite = Sprite::from_atlas_image(
+    assets.image.clone(),
+    TextureAtlas {
+      layout: assets.layout.clone(),
+      index: new_roll - 1,
+    },
+  );
+
+  sprite.color = color;
+
+  sprite.custom_size = Some(Vec2::splat(DIE_SIZE));
+
+  commands.spawn((
+    sprite,
+    Transform::from_xyz(
+      (column - (DICE_PER_ROW - 1) as f32 / 2.) * DIE_SPACING,
+      DICE_TOP - row * DIE_SPACING,
+      1.,
+    ),
+    HandDie,
+    Tumble::new(new_roll, seconds),
+  ));
+}
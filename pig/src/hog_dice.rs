@@ -0,0 +1,12 @@
+use ::bevy::prelude::*;
+use ::pig::model::variant::HOG_DEFAULT_DICE;
+
+/// How many dice the next Hog roll uses
+#[derive(Resource)]
+pub struct HogDice(pub usize);
+
+impl Default for HogDice {
+  fn default() -> Self {
+    Self(HOG_DEFAULT_DICE)
+  }
+}
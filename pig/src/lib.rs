@@ -0,0 +1,4 @@
+pub mod model;
+pub mod network;
+pub mod strategies;
+pub mod tournament;
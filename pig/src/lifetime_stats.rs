@@ -0,0 +1,146 @@
+use super::player_kind::PlayerKind;
+use super::player_stats::PlayerStats;
+use super::roster::Roster;
+use ::bevy::prelude::*;
+use ::my_lib::persistence;
+use ::pig::model::pig_game::PigGame;
+use ::pig::model::turn_end::TurnEnd;
+use ::serde::{Deserialize, Serialize};
+use ::std::collections::BTreeMap;
+use ::std::path::{Path, PathBuf};
+
+pub const FILE_VERSION: u32 = 2;
+
+/// How each human at this machine has done over every finished game, by
+/// name, so that players sharing the machine each keep their own record
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Resource, Serialize)]
+pub struct LifetimeStats {
+  pub players: BTreeMap<String, PlayerStats>,
+  pub version: u32,
+}
+
+impl LifetimeStats {
+  /// The statistics in the platform data directory, if there is one
+  pub fn default_path() -> Option<PathBuf> {
+    persistence::data_path("pig-stats.json")
+  }
+
+  /// A missing, corrupt or newer file, or one from before the statistics
+  /// were kept by name, starts them over
+  pub fn load(path: &Path) -> Self {
+    persistence::load_json::<Self>(path)
+      .filter(|stats| stats.version <= FILE_VERSION)
+      .unwrap_or_default()
+  }
+
+  /// Adds a finished game to the record of each human here who played in
+  /// it.  Only the one who won it gets the win.
+  pub fn record(
+    &mut self,
+    game: &PigGame,
+    roster: &Roster,
+  ) {
+    roster
+      .players
+      .iter()
+      .enumerate()
+      .filter(|(_, entry)| entry.kind == PlayerKind::Human)
+      .for_each(|(player, entry)| {
+        let stats: &mut PlayerStats =
+          self.players.entry(entry.name.clone()).or_default();
+
+        stats.games += 1;
+
+        if game.winner() == Some(player) {
+          stats.wins += 1;
+        }
+
+        game
+          .history()
+          .iter()
+          .filter(|record| record.player == player)
+          .for_each(|record| {
+            stats.turns += 1;
+
+            if let TurnEnd::Banked(points) = record.end {
+              stats.points += points;
+            }
+          });
+      });
+  }
+
+  pub fn save(
+    &self,
+    path: &Path,
+  ) -> ::anyhow::Result<()> {
+    persistence::save_json(self, path)
+  }
+}
+
+impl Default for LifetimeStats {
+  fn default() -> Self {
+    Self {
+      players: BTreeMap::new(),
+      version: FILE_VERSION,
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::super::roster_entry::RosterEntry;
+  use super::*;
+
+  #[test]
+  fn test_record() {
+    let mut game: PigGame = PigGame::new(2, 10);
+
+    game.roll(&[4]);
+    game.hold();
+    game.roll(&[1]);
+    game.roll(&[1]);
+    game.roll(&[5]);
+    game.roll(&[5]);
+    game.hold();
+
+    let mut stats: LifetimeStats = LifetimeStats::default();
+
+    stats.record(&game, &Roster::default());
+
+    let player: &PlayerStats = &stats.players["Player"];
+
+    assert_eq!(stats.players.len(), 1);
+    assert_eq!(player.games, 1);
+    assert_eq!(player.wins, 0);
+    assert_eq!(player.turns, 2);
+    assert_eq!(player.average_turn_total(), 2.);
+  }
+
+  #[test]
+  fn test_record_hot_seat() {
+    let mut game: PigGame = PigGame::new(2, 10);
+
+    game.roll(&[6]);
+    game.roll(&[6]);
+    game.hold();
+
+    let mut roster: Roster = Roster::default();
+
+    roster.players[1] = RosterEntry {
+      color: roster.players[1].color,
+      kind: PlayerKind::Human,
+      name: "Other".to_string(),
+    };
+
+    let mut stats: LifetimeStats = LifetimeStats::default();
+
+    stats.record(&game, &roster);
+
+    assert_eq!(game.winner(), Some(0));
+    assert_eq!(stats.players["Player"].wins, 1);
+    assert_eq!(stats.players["Player"].win_rate(), 1.);
+    assert_eq!(stats.players["Other"].games, 1);
+    assert_eq!(stats.players["Other"].wins, 0);
+    assert_eq!(stats.players["Other"].turns, 0);
+  }
+}
@@ -1,65 +1,169 @@
-use self::final_score::FinalScore;
+use self::advisor::Advisor;
+use self::banked_points::BankedPoints;
+use self::bust_notice::BustNotice;
+use self::cpu_pace::CpuPace;
+use self::cpu_strategy::CpuStrategy;
+use self::current_game::CurrentGame;
+use self::fast_forward::FastForward;
 use self::game_assets::GameAssets;
-use self::game_element::GameElement;
+use self::game_log_file::GameLogFile;
 use self::game_phase::GamePhase;
 use self::hand_die::HandDie;
 use self::hand_timer::HandTimer;
-use self::scores::Scores;
+use self::hog_dice::HogDice;
+use self::lifetime_stats::LifetimeStats;
+use self::network_client::NetworkClient;
+use self::network_host::NetworkHost;
+use self::resume::Resume;
+use self::roster::Roster;
+use self::saved_game::SavedGame;
+use self::score_bar::ScoreBar;
+use self::score_label::ScoreLabel;
+use self::selected_rules::SelectedRules;
+use self::show_hints::ShowHints;
+use self::tumble::Tumble;
+use self::turn::Turn;
+use self::turn_phase::TurnPhase;
+use self::turn_readout::TurnReadout;
 use ::bevy::prelude::*;
-use ::bevy::window::WindowResolution;
-use ::bevy_egui::{EguiContexts, EguiPlugin, EguiPrimaryContextPass, egui};
+use ::bevy_egui::{
+  EguiContexts, EguiGlobalSettings, EguiPlugin, EguiPrimaryContextPass, egui,
+};
+use ::my_lib::add_phase;
 use ::my_lib::game_state_plugin::GameStatePlugin;
+use ::my_lib::high_scores::high_score_plugin::HighScorePlugin;
+use ::my_lib::menu_controls::{self, MenuControls};
+use ::my_lib::pause::pause_state::PauseState;
+use ::my_lib::persistence;
+use ::my_lib::phase_graph::PhaseGraph;
 use ::my_lib::random::RandomNumberGenerator;
 use ::my_lib::random_plugin::RandomPlugin;
-use ::my_lib::{add_phase, cleanup};
+use ::my_lib::settings::game_settings::GameSettings;
+use ::my_lib::settings::settings_plugin::SettingsPlugin;
+use ::my_lib::state_scope_plugin::StateScopePlugin;
+use ::my_lib::transitions::transition::Transition;
+use ::my_lib::transitions::transition_plugin::TransitionPlugin;
+use ::pig::model::pig_game::PigGame;
+use ::pig::model::roll_odds::RollOdds;
+use ::pig::model::roll_outcome::RollOutcome;
+use ::pig::model::variant::{HOG_MAX_DICE, Variant};
+use ::pig::strategies::strategy::Strategy;
+use ::pig::strategies::strategy_kind::StrategyKind;
 
-mod final_score;
+mod advisor;
+mod banked_points;
+mod bust_notice;
+mod cpu_pace;
+mod cpu_strategy;
+mod current_game;
+mod fast_forward;
 mod game_assets;
-mod game_element;
+mod game_log_file;
 mod game_phase;
 mod hand_die;
 mod hand_timer;
+mod hog_dice;
+mod lifetime_stats;
+mod network_client;
+mod network_form;
+mod network_host;
+mod player_kind;
+mod player_stats;
 mod random;
-mod scores;
+mod resume;
+mod roster;
+mod roster_entry;
+mod saved_game;
+mod score_bar;
+mod score_label;
+mod selected_rules;
+mod show_hints;
+mod tumble;
+mod turn;
+mod turn_phase;
+mod turn_readout;
 
-fn main() {
-  let mut app: App = App::new();
+/// How far the scoreboard sits from the top right corner of the window
+const HUD_MARGIN: f32 = 16.;
 
-  add_phase!(app, GamePhase, GamePhase::Start,
-      start => [ setup ],
-      run => [ start_game ],
-      exit => [ ]
-  );
+const HUD_WIDTH: f32 = 240.;
 
-  add_phase!(app, GamePhase, GamePhase::Player,
-      start => [ ],
-      run => [ check_game_over ],
-      exit => [ ]
-  );
+fn main() {
+  let phase_graph: PhaseGraph<GamePhase> = GamePhase::graph();
 
-  add_phase!(app, GamePhase, GamePhase::Cpu,
-      start => [ ],
-      run => [ cpu, check_game_over, display_score ],
-      exit => [ ]
-  );
+  if phase_graph.print_requested_diagram() {
+    return;
+  }
 
-  add_phase!(app, GamePhase, GamePhase::End,
-      start => [ ],
-      run => [ end_game ],
-      exit => [ cleanup::<GameElement> ]
-  );
+  let mut app: App = App::new();
 
-  add_phase!(app, GamePhase, GamePhase::GameOver,
-      start => [ ],
-      run => [ display_final_score ],
+  add_phase!(app, GamePhase, GamePhase::Playing,
+      start => [ setup ],
+      run chain => [
+        roll.run_if(
+          turn::in_turn_phase(TurnPhase::Rolling)
+            .and(not(resource_exists::<NetworkClient>))
+        ),
+        tumble::animate,
+        settle.run_if(turn::in_turn_phase(TurnPhase::Tumbling)),
+        bust.run_if(turn::in_turn_phase(TurnPhase::Busted)),
+        bank.run_if(
+          turn::in_turn_phase(TurnPhase::Banking)
+            .and(not(resource_exists::<NetworkClient>))
+        ),
+        banked_points::animate
+      ],
+      run(Update, resource_exists::<NetworkClient>) => [
+        network_client::request.run_if(
+          turn::in_turn_phase(TurnPhase::Rolling)
+            .or(turn::in_turn_phase(TurnPhase::Banking))
+        )
+      ],
+      run(Update, resource_exists::<NetworkHost>) => [
+        network_host::remote.run_if(
+          turn::in_turn_phase(TurnPhase::Deciding)
+            .and(turn::is_remote_turn)
+            .and(turn::is_game_turn)
+        )
+      ],
+      run(
+        Update,
+        resource_changed::<CurrentGame>,
+        not(resource_exists::<NetworkClient>)
+      ) => [ autosave ],
+      run(Update) => [ start_turn ],
+      run(Update, turn::is_cpu_turn, in_state(PauseState::Running)) chain => [
+        skip_ahead,
+        cpu.run_if(
+          turn::in_turn_phase(TurnPhase::Deciding).and(turn::is_game_turn)
+        )
+      ],
+      run(
+        Update,
+        resource_changed::<CurrentGame>.or(resource_changed::<Roster>)
+      ) => [ score_bar::fill, score_label::update, turn_readout::update ],
+      run(Update) => [ toggle_hints ],
+      run(EguiPrimaryContextPass, resource_equals(ShowHints(true))) => [
+        display_hints
+      ],
+      run(EguiPrimaryContextPass, in_state(PauseState::Running)) => [
+        cpu_turn.run_if(turn::is_cpu_turn),
+        player.run_if(
+          turn::in_turn_phase(TurnPhase::Deciding)
+            .and(turn::is_human_turn)
+            .and(turn::is_game_turn)
+        )
+      ],
       exit => [ ]
   );
 
-  let resolution: WindowResolution = WindowResolution::new(1024, 768);
+  let settings_plugin: SettingsPlugin = SettingsPlugin::load("pig")
+    .bind("hints", KeyCode::KeyH)
+    .bind("skip", KeyCode::KeyF);
 
   let primary_window: Window = Window {
     title: "Pig".into(),
-    resolution,
+    resolution: settings_plugin.settings.window_resolution(),
     ..default()
   };
 
@@ -70,198 +174,749 @@ fn main() {
 
   let game_state_plugin: GameStatePlugin<GamePhase> = GameStatePlugin {
     menu_state: GamePhase::MainMenu,
-    game_start_state: GamePhase::Start,
+    game_start_state: GamePhase::Playing,
+    menu_controls: MenuControls::default(),
     game_end_state: GamePhase::GameOver,
   };
 
+  let state_scope_plugin: StateScopePlugin<GamePhase> =
+    StateScopePlugin::default();
+
   let egui_plugin: EguiPlugin = EguiPlugin::default();
 
+  let transition_plugin: TransitionPlugin<GamePhase> =
+    TransitionPlugin::default()
+      .add_state(GamePhase::GameOver, Transition::cross_fade(1.))
+      .add_state(GamePhase::MainMenu, Transition::fade(Color::BLACK, 0.6));
+
   app
     .add_plugins(DefaultPlugins.set(window_plugin))
     .add_plugins(game_state_plugin)
     .add_plugins(egui_plugin)
     .add_plugins(RandomPlugin)
+    .add_plugins(HighScorePlugin::new("pig"))
+    .add_plugins(transition_plugin)
+    .add_plugins(settings_plugin)
+    .add_plugins(phase_graph)
+    .add_plugins(state_scope_plugin)
+    .add_sub_state::<Turn>()
+    .init_resource::<Advisor>()
+    .init_resource::<CpuPace>()
+    .init_resource::<CpuStrategy>()
+    .init_resource::<FastForward>()
+    .init_resource::<HogDice>()
+    .init_resource::<Roster>()
+    .init_resource::<SelectedRules>()
+    .init_resource::<ShowHints>()
+    .insert_resource(
+      LifetimeStats::default_path()
+        .map(|path| LifetimeStats::load(&path))
+        .unwrap_or_default(),
+    )
+    // Keeps typing in a name field from also pressing the menu keys
+    .insert_resource(EguiGlobalSettings {
+      enable_absorb_bevy_input_system: true,
+      ..default()
+    })
+    .add_systems(
+      EguiPrimaryContextPass,
+      (choose_rules, choose_strategy, edit_roster)
+        .run_if(in_state(GamePhase::MainMenu)),
+    )
+    .add_systems(Update, (advisor::poll, cpu_strategy::poll))
+    .add_systems(
+      Update,
+      (
+        network_host::poll,
+        network_host::broadcast.run_if(
+          resource_exists::<CurrentGame>.and(
+            in_state(GamePhase::Playing).or(in_state(GamePhase::GameOver)),
+          ),
+        ),
+      )
+        .chain()
+        .run_if(resource_exists::<NetworkHost>),
+    )
+    .add_systems(
+      Update,
+      network_client::sync.run_if(resource_exists::<NetworkClient>),
+    )
+    .add_systems(EguiPrimaryContextPass, network_form::window)
+    .add_systems(OnEnter(GamePhase::MainMenu), find_saved_game)
+    .add_systems(
+      OnEnter(GamePhase::GameOver),
+      (forget_saved_game, record_stats),
+    )
+    .add_systems(
+      EguiPrimaryContextPass,
+      continue_game.run_if(
+        in_state(GamePhase::MainMenu).and(resource_exists::<SavedGame>),
+      ),
+    )
     // .add_systems(Startup, setup)
-    .add_systems(EguiPrimaryContextPass, display_score)
-    .init_state::<GamePhase>()
-    // .add_systems(EguiPrimaryContextPass, player)
     .add_systems(
       EguiPrimaryContextPass,
-      player.run_if(in_state(GamePhase::Player)),
+      display_log.run_if(
+        in_state(GamePhase::Playing).or(in_state(GamePhase::GameOver)),
+      ),
     )
     // .add_systems(Update, cpu.run_if(in_state(GamePhase::Cpu)))
     .run();
 }
 
-fn check_game_over(
-  scores: Res<Scores>,
-  mut state: ResMut<NextState<GamePhase>>,
+/// Saves the game whenever it changes so that quitting does not lose it
+fn autosave(
+  cpu_strategy: Res<CpuStrategy>,
+  game: Res<CurrentGame>,
+  hog_dice: Res<HogDice>,
+  rng: Res<RandomNumberGenerator>,
+  roster: Res<Roster>,
+  rules: Res<SelectedRules>,
 ) {
-  if scores.cpu >= 100 || scores.player >= 100 {
-    state.set(GamePhase::End);
+  if game.winner().is_some() {
+    return;
+  }
+
+  let Some(path) = SavedGame::default_path() else {
+    return;
+  };
+
+  let saved_game: SavedGame = SavedGame {
+    cpu_strategy: cpu_strategy.kind,
+    game: game.0.clone(),
+    hog_dice: hog_dice.0,
+    rng: rng.clone(),
+    roster: roster.clone(),
+    rules: rules.0,
+    version: saved_game::FILE_VERSION,
+  };
+
+  if let Err(error) = saved_game.save(&path) {
+    warn!("Could not save the game to {}: {error}", path.display());
   }
 }
 
-fn clear_die(
-  hand_query: &Query<(Entity, &Sprite), With<HandDie>>,
-  commands: &mut Commands,
+#[expect(clippy::too_many_arguments)]
+fn bank(
+  hand_query: Query<Entity, With<HandDie>>,
+  mut commands: Commands,
+  mut game: ResMut<CurrentGame>,
+  roster: Res<Roster>,
+  mut state: ResMut<NextState<GamePhase>>,
+  turn: Res<State<Turn>>,
+  mut turn_state: ResMut<NextState<Turn>>,
+  window: Single<&Window>,
 ) {
-  hand_query
-    .iter()
-    .for_each(|(entity, _)| commands.entity(entity).despawn());
+  hand_die::clear(&hand_query, &mut commands);
+
+  let banked: usize = game.turn_total();
+
+  game.hold();
+
+  // The points fly off toward the scoreboard in the top right corner while
+  // play carries on
+  if banked > 0 {
+    commands.spawn((
+      BankedPoints {
+        from: Vec3::new(0., hand_die::DICE_TOP, 2.),
+        timer: Timer::from_seconds(0.8, TimerMode::Once),
+        to: Vec3::new(
+          window.width() / 2. - HUD_MARGIN - HUD_WIDTH / 2.,
+          window.height() / 2. - HUD_MARGIN - 60.,
+          2.,
+        ),
+      },
+      Text2d::new(format!("+{banked}")),
+      TextColor(roster.players[turn.player].color),
+      TextFont {
+        font_size: 64.,
+        ..default()
+      },
+      Transform::from_xyz(0., hand_die::DICE_TOP, 2.),
+    ));
+  }
+
+  turn::end_turn(&mut commands, &game, &roster, &mut state, &mut turn_state);
 }
 
+/// Shows why the hand was lost, then clears the table and moves on
 #[expect(clippy::too_many_arguments)]
-fn cpu(
-  hand_query: Query<(Entity, &Sprite), With<HandDie>>,
-  mut state: ResMut<NextState<GamePhase>>,
-  mut scores: ResMut<Scores>,
-  rng: Res<RandomNumberGenerator>,
+fn bust(
+  hand_query: Query<Entity, With<HandDie>>,
   mut commands: Commands,
-  assets: Res<GameAssets>,
-  mut timer: ResMut<HandTimer>,
+  game: Res<CurrentGame>,
+  mut notice_query: Query<(Entity, &mut BustNotice)>,
+  roster: Res<Roster>,
+  mut state: ResMut<NextState<GamePhase>>,
   time: Res<Time>,
+  mut turn_state: ResMut<NextState<Turn>>,
 ) {
-  timer.0.tick(time.delta());
+  let Ok((entity, mut notice)) = notice_query.single_mut() else {
+    return;
+  };
 
-  if timer.0.just_finished() {
-    let hand_total: usize = hand_query
-      .iter()
-      .map(|(_, ts)| ts.texture_atlas.as_ref().unwrap().index + 1)
-      .sum();
+  if notice.0.tick(time.delta()).just_finished() {
+    commands.entity(entity).despawn();
 
-    if hand_total < 20 && scores.cpu + hand_total < 100 {
-      let new_roll: u32 = rng.range(1..7);
+    hand_die::clear(&hand_query, &mut commands);
 
-      if new_roll == 1 {
-        clear_die(&hand_query, &mut commands);
+    turn::end_turn(&mut commands, &game, &roster, &mut state, &mut turn_state);
+  }
+}
 
-        state.set(GamePhase::Player);
-      } else {
-        spawn_die(
-          &hand_query,
-          &mut commands,
-          &assets,
-          new_roll as usize,
-          Color::Srgba(Srgba::new(0., 0., 1., 1.)),
-        );
+fn choose_rules(
+  mut egui_contexts: EguiContexts,
+  mut rules: ResMut<SelectedRules>,
+) -> Result {
+  let Ok(egui_context) = egui_contexts.ctx_mut() else {
+    return Ok(());
+  };
+
+  egui::Window::new("Rules").show(egui_context, |ui: &mut egui::Ui| {
+    Variant::all().into_iter().for_each(|variant| {
+      ui.radio_value(&mut rules.variant, variant, variant.label());
+    });
+
+    ui.checkbox(&mut rules.finish_round, "Finish the round");
+
+    ui.add(
+      egui::Slider::new(&mut rules.target, selected_rules::TARGETS)
+        .step_by(10.)
+        .text("Target"),
+    );
+  });
+
+  Ok(())
+}
+
+fn choose_strategy(
+  mut cpu_pace: ResMut<CpuPace>,
+  mut cpu_strategy: ResMut<CpuStrategy>,
+  mut egui_contexts: EguiContexts,
+  rules: Res<SelectedRules>,
+) -> Result {
+  let Ok(egui_context) = egui_contexts.ctx_mut() else {
+    return Ok(());
+  };
+
+  let mut kind: StrategyKind = cpu_strategy.kind;
+
+  egui::Window::new("CPU Difficulty").show(
+    egui_context,
+    |ui: &mut egui::Ui| {
+      StrategyKind::all().into_iter().for_each(|choice| {
+        ui.radio_value(&mut kind, choice, choice.label());
+      });
+
+      if cpu_strategy.pending.is_some() {
+        ui.label("Preparing...");
       }
-    } else {
-      scores.cpu += hand_total;
 
-      state.set(GamePhase::Player);
+      ui.separator();
 
-      hand_query
-        .iter()
-        .for_each(|(entity, _)| commands.entity(entity).despawn());
-    }
+      ui.label("Pace");
+
+      CpuPace::all().into_iter().for_each(|pace| {
+        ui.radio_value(&mut *cpu_pace, pace, pace.label());
+      });
+    },
+  );
+
+  if kind != cpu_strategy.kind || rules.target != cpu_strategy.target {
+    cpu_strategy.choose(kind, rules.target);
   }
+
+  Ok(())
 }
 
-fn display_final_score(
+/// Offers to pick up the saved game where it was left
+#[expect(clippy::too_many_arguments)]
+fn continue_game(
+  mut commands: Commands,
+  mut cpu_strategy: ResMut<CpuStrategy>,
   mut egui_contexts: EguiContexts,
-  scores: Res<FinalScore>,
-) {
+  mut hog_dice: ResMut<HogDice>,
+  mut roster: ResMut<Roster>,
+  mut rules: ResMut<SelectedRules>,
+  saved_game: Res<SavedGame>,
+  mut state: ResMut<NextState<GamePhase>>,
+) -> Result {
   let Ok(egui_context) = egui_contexts.ctx_mut() else {
-    return;
+    return Ok(());
   };
 
-  egui::Window::new("Total Scores").show(egui_context, |ui| {
-    ui.label(format!("Player: {}", scores.0.player));
+  let mut continued: bool = false;
+
+  egui::Window::new("Saved Game").show(egui_context, |ui: &mut egui::Ui| {
+    saved_game
+      .roster
+      .players
+      .iter()
+      .zip(saved_game.game.scores())
+      .for_each(|(entry, score)| {
+        ui.label(format!("{}: {score}", entry.name));
+      });
+
+    continued = ui.button("Continue").clicked();
+  });
+
+  if !continued {
+    return Ok(());
+  }
+
+  *roster = saved_game.roster.clone();
+
+  rules.0 = saved_game.rules;
+
+  hog_dice.0 = saved_game.hog_dice;
 
-    ui.label(format!("CPU: {}", scores.0.cpu));
+  cpu_strategy.choose(saved_game.cpu_strategy, saved_game.rules.target);
 
-    if scores.0.player < scores.0.cpu {
-      ui.label("CPU is the winner!");
+  commands.insert_resource(saved_game.rng.clone());
+
+  commands.insert_resource(Resume(saved_game.game.clone()));
+
+  commands.remove_resource::<SavedGame>();
+
+  state.set(GamePhase::Playing);
+
+  Ok(())
+}
+
+#[expect(clippy::too_many_arguments)]
+fn cpu(
+  cpu_strategy: Res<CpuStrategy>,
+  fast_forward: Res<FastForward>,
+  game: Res<CurrentGame>,
+  mut hog_dice: ResMut<HogDice>,
+  mut timer: ResMut<HandTimer>,
+  time: Res<Time>,
+  turn: Res<State<Turn>>,
+  mut turn_state: ResMut<NextState<Turn>>,
+) {
+  timer.0.tick(time.delta());
+
+  if fast_forward.0 || timer.0.just_finished() {
+    if !game.must_hold() && cpu_strategy.strategy.should_roll(&game) {
+      hog_dice.0 = cpu_strategy.strategy.dice(&game);
+
+      turn_state.set(turn.with_phase(TurnPhase::Rolling));
     } else {
-      ui.label("Player is the winner!");
+      turn_state.set(turn.with_phase(TurnPhase::Banking));
     }
-  });
+  }
 }
 
-fn display_score(
-  scores: Res<Scores>,
+/// Says whose turn it is while the CPU plays, with a button to skip ahead
+fn cpu_turn(
   mut egui_contexts: EguiContexts,
+  mut fast_forward: ResMut<FastForward>,
+  roster: Res<Roster>,
+  settings: Res<GameSettings>,
+  turn: Res<State<Turn>>,
 ) -> Result {
   let Ok(egui_context) = egui_contexts.ctx_mut() else {
     return Ok(());
   };
 
-  egui::Window::new("Total Scores").show(egui_context, |ui: &mut egui::Ui| {
-    ui.label(format!("Player: {}", scores.player));
+  let skip: String = match settings.key("skip") {
+    Some(key) => format!("Skip Ahead ({})", menu_controls::key_label(key)),
+    None => "Skip Ahead".into(),
+  };
+
+  egui::Window::new("CPU Turn").show(egui_context, |ui: &mut egui::Ui| {
+    ui.label(format!("{} is playing", roster.players[turn.player].name));
 
-    ui.label(format!("CPU: {}", scores.cpu));
+    if !fast_forward.0 && ui.button(skip).clicked() {
+      fast_forward.0 = true;
+    }
   });
 
   Ok(())
 }
 
-fn end_game(
-  mut commands: Commands,
-  scores: Res<Scores>,
-  mut state: ResMut<NextState<GamePhase>>,
-) {
-  commands.insert_resource(FinalScore(*scores));
+/// The odds of rolling again for whoever's turn it is, what the optimal
+/// policy would do, and how that player has done over time if they are a
+/// human here
+fn display_hints(
+  mut advisor: ResMut<Advisor>,
+  game: Res<CurrentGame>,
+  hog_dice: Res<HogDice>,
+  roster: Res<Roster>,
+  settings: Res<GameSettings>,
+  stats: Res<LifetimeStats>,
+  mut egui_contexts: EguiContexts,
+) -> Result {
+  let Ok(egui_context) = egui_contexts.ctx_mut() else {
+    return Ok(());
+  };
+
+  if game.variant() == Variant::Standard {
+    advisor.prepare(game.target());
+  }
+
+  let dice: usize = game.variant().dice().unwrap_or(hog_dice.0);
+
+  let odds: RollOdds = RollOdds::new(&game, dice);
+
+  egui::Window::new("Hints")
+    .default_pos([
+      20., 200.,
+    ])
+    .show(egui_context, |ui: &mut egui::Ui| {
+      if game.must_hold() {
+        ui.label("Hog only rolls once, so hold");
+      } else {
+        ui.label(format!("Chance to bust: {:.1}%", odds.bust * 100.));
+
+        ui.label(format!(
+          "Rolling is worth {:+.2} on average",
+          odds.expected_gain
+        ));
+      }
 
-  state.set(GamePhase::GameOver);
+      let advice: String = match &advisor.optimal {
+        _ if game.variant() != Variant::Standard => {
+          format!("No optimal policy for {}", game.variant().label())
+        },
+        Some(optimal) => {
+          let play: &str = if optimal.should_roll(&game) {
+            "roll"
+          } else {
+            "hold"
+          };
+
+          let chance: f64 = optimal.win_chance(&game).unwrap_or_default();
+
+          format!("Optimal play: {play} (win chance {:.1}%)", chance * 100.)
+        },
+        None => "Solving the optimal policy...".into(),
+      };
+
+      ui.label(advice);
+
+      if roster.is_human(game.current())
+        && let Some(entry) = roster.players.get(game.current())
+        && let Some(stats) = stats.players.get(&entry.name)
+      {
+        ui.separator();
+
+        ui.label(format!(
+          "{}'s lifetime: {} games, {} wins ({:.0}%)",
+          entry.name,
+          stats.games,
+          stats.wins,
+          stats.win_rate() * 100.
+        ));
+
+        ui.label(format!(
+          "Average turn total: {:.1}",
+          stats.average_turn_total()
+        ));
+      }
+
+      if let Some(key) = settings.key("hints") {
+        ui.separator();
+
+        ui.label(format!("Press {} to hide", menu_controls::key_label(key)));
+      }
+    });
+
+  Ok(())
 }
 
-fn player(
-  hand_query: Query<(Entity, &Sprite), With<HandDie>>,
-  mut commands: Commands,
-  rng: Res<RandomNumberGenerator>,
-  assets: Res<GameAssets>,
-  mut scores: ResMut<Scores>,
-  mut state: ResMut<NextState<GamePhase>>,
+/// The turn history so far, with buttons to save it once the game is over
+fn display_log(
+  mut exported: Local<Option<String>>,
+  game: Res<CurrentGame>,
+  phase: Res<State<GamePhase>>,
+  roster: Res<Roster>,
   mut egui_contexts: EguiContexts,
 ) -> Result {
   let Ok(egui_context) = egui_contexts.ctx_mut() else {
     return Ok(());
   };
 
-  egui::Window::new("Play Options").show(egui_context, |ui: &mut egui::Ui| {
-    let hand_score: usize = hand_query
-      .iter()
-      .map(|(_, ts)| ts.texture_atlas.as_ref().unwrap().index + 1)
-      .sum();
+  let log_file: GameLogFile = GameLogFile::new(&game, &roster);
 
-    ui.label(format!("Score for this hand: {hand_score}"));
+  egui::Window::new("Game Log")
+    .default_pos([
+      20., 400.,
+    ])
+    .show(egui_context, |ui: &mut egui::Ui| {
+      egui::ScrollArea::vertical()
+        .max_height(200.)
+        .stick_to_bottom(true)
+        .show(ui, |ui: &mut egui::Ui| {
+          log_file.lines().into_iter().for_each(|line| {
+            ui.label(line);
+          });
+        });
 
-    if ui.button("Roll Dice").clicked() {
-      let new_roll: usize = rng.range(1..=6);
+      if *phase.get() != GamePhase::GameOver {
+        *exported = None;
 
-      if new_roll == 1 {
-        clear_die(&hand_query, &mut commands);
+        return;
+      }
 
-        state.set(GamePhase::Cpu);
-      } else {
-        spawn_die(&hand_query, &mut commands, &assets, new_roll, Color::WHITE)
+      ui.horizontal(|ui: &mut egui::Ui| {
+        let text_clicked: bool = ui.button("Export Text").clicked();
+
+        let json_clicked: bool = ui.button("Export JSON").clicked();
+
+        let file_name: &str = match (text_clicked, json_clicked) {
+          (true, _) => "pig-game-log.txt",
+          (_, true) => "pig-game-log.json",
+          _ => return,
+        };
+
+        let Some(path) = persistence::data_path(file_name) else {
+          *exported = Some("No data directory to export to".into());
+
+          return;
+        };
+
+        let result: ::anyhow::Result<()> = if text_clicked {
+          log_file.save_text(&path)
+        } else {
+          log_file.save_json(&path)
+        };
+
+        *exported = Some(match result {
+          Ok(()) => format!("Saved {}", path.display()),
+          Err(error) => format!("Could not save {}: {error}", path.display()),
+        });
+      });
+
+      if let Some(message) = &*exported {
+        ui.label(message);
       }
+    });
+
+  Ok(())
+}
+
+fn edit_roster(
+  mut egui_contexts: EguiContexts,
+  host: Option<Res<NetworkHost>>,
+  mut roster: ResMut<Roster>,
+) -> Result {
+  let Ok(egui_context) = egui_contexts.ctx_mut() else {
+    return Ok(());
+  };
+
+  let mut removed: Option<usize> = None;
+
+  let can_remove: bool = roster.players.len() > roster::MIN_PLAYERS;
+
+  egui::Window::new("Players").show(egui_context, |ui: &mut egui::Ui| {
+    roster
+      .players
+      .iter_mut()
+      .enumerate()
+      .for_each(|(player, entry)| {
+        ui.horizontal(|ui: &mut egui::Ui| {
+          let mut rgb: [f32; 3] =
+            entry.color.to_srgba().to_f32_array_no_alpha();
+
+          if ui.color_edit_button_rgb(&mut rgb).changed() {
+            entry.color = Color::srgb(rgb[0], rgb[1], rgb[2]);
+          }
+
+          ui.text_edit_singleline(&mut entry.name);
+
+          if ui.button(entry.kind.label()).clicked() {
+            entry.kind = entry.kind.next();
+          }
+
+          if can_remove && ui.button("Remove").clicked() {
+            removed = Some(player);
+          }
+        });
+      });
+
+    if roster.players.len() < roster::MAX_PLAYERS
+      && ui.button("Add Player").clicked()
+    {
+      roster.add();
     }
 
-    if ui.button("Pass - Keep Hand Score").clicked() {
-      let hand_total: usize = hand_query
-        .iter()
-        .map(|(_, ts)| ts.texture_atlas.as_ref().unwrap().index + 1)
-        .sum();
+    let has_remote: bool =
+      (0..roster.players.len()).any(|player| roster.is_remote(player));
+
+    if has_remote && host.is_none() {
+      ui.label("The CPU plays remote seats unless this game is hosted");
+    }
+  });
+
+  if let Some(player) = removed {
+    roster.remove(player);
+  }
+
+  Ok(())
+}
+
+fn find_saved_game(mut commands: Commands) {
+  match SavedGame::default_path().and_then(|path| SavedGame::load(&path)) {
+    Some(saved_game) => commands.insert_resource(saved_game),
+    None => commands.remove_resource::<SavedGame>(),
+  }
+}
+
+fn forget_saved_game() {
+  let Some(path) = SavedGame::default_path() else {
+    return;
+  };
+
+  if let Err(error) = SavedGame::delete(&path) {
+    warn!("Could not delete {}: {error}", path.display());
+  }
+}
+
+#[expect(clippy::too_many_arguments)]
+fn player(
+  game: Res<CurrentGame>,
+  mut hog_dice: ResMut<HogDice>,
+  roster: Res<Roster>,
+  settings: Res<GameSettings>,
+  mut show_hints: ResMut<ShowHints>,
+  turn: Res<State<Turn>>,
+  mut turn_state: ResMut<NextState<Turn>>,
+  mut egui_contexts: EguiContexts,
+) -> Result {
+  let Ok(egui_context) = egui_contexts.ctx_mut() else {
+    return Ok(());
+  };
+
+  let title: String =
+    format!("{} - Play Options", roster.players[turn.player].name);
+
+  let hints: String = match settings.key("hints") {
+    Some(key) => format!("Show hints ({})", menu_controls::key_label(key)),
+    None => "Show hints".into(),
+  };
 
-      scores.player += hand_total;
+  egui::Window::new(title).show(egui_context, |ui: &mut egui::Ui| {
+    ui.label(format!("Score for this hand: {}", game.turn_total()));
 
-      clear_die(&hand_query, &mut commands);
+    // Hog players choose how many dice to throw, but only get the one throw
+    if game.variant() == Variant::Hog && game.hand().is_empty() {
+      ui.add(egui::Slider::new(&mut hog_dice.0, 1..=HOG_MAX_DICE).text("Dice"));
+    }
 
-      state.set(GamePhase::Cpu);
+    if !game.must_hold() && ui.button("Roll Dice").clicked() {
+      turn_state.set(turn.with_phase(TurnPhase::Rolling));
+    }
+
+    if ui.button("Pass - Keep Hand Score").clicked() {
+      turn_state.set(turn.with_phase(TurnPhase::Banking));
     }
+
+    ui.checkbox(&mut show_hints.0, hints);
   });
 
   Ok(())
 }
 
+fn record_stats(
+  game: Res<CurrentGame>,
+  roster: Res<Roster>,
+  mut stats: ResMut<LifetimeStats>,
+) {
+  stats.record(&game, &roster);
+
+  let Some(path) = LifetimeStats::default_path() else {
+    return;
+  };
+
+  if let Err(error) = stats.save(&path) {
+    warn!("Could not save statistics to {}: {error}", path.display());
+  }
+}
+
+#[expect(clippy::too_many_arguments)]
+fn roll(
+  hand_query: Query<Entity, With<HandDie>>,
+  mut commands: Commands,
+  rng: Res<RandomNumberGenerator>,
+  assets: Res<GameAssets>,
+  fast_forward: Res<FastForward>,
+  mut game: ResMut<CurrentGame>,
+  hog_dice: Res<HogDice>,
+  roster: Res<Roster>,
+  turn: Res<State<Turn>>,
+  mut turn_state: ResMut<NextState<Turn>>,
+) {
+  let count: usize = game.variant().dice().unwrap_or(hog_dice.0);
+
+  let dice: Vec<usize> = (0..count).map(|_| rng.range(1..=6)).collect();
+
+  // The dice go on the table after the hand, even if they lose it
+  let first: usize = hand_query.iter().count();
+
+  dice.iter().enumerate().for_each(|(offset, die)| {
+    let seconds: f32 = if fast_forward.0 {
+      0.
+    } else {
+      tumble::SECONDS + offset as f32 * tumble::STAGGER_SECONDS
+    };
+
+    hand_die::spawn(
+      &mut commands,
+      &assets,
+      first + offset,
+      *die,
+      roster.players[turn.player].color,
+      seconds,
+    );
+  });
+
+  let message: Option<&str> = match game.roll(&dice) {
+    RollOutcome::Added(_) => None,
+    RollOutcome::LostScore => Some("Snake eyes! Score lost!"),
+    RollOutcome::PiggedOut => Some("Pigged out!"),
+  };
+
+  if let Some(message) = message {
+    bust_notice::spawn(&mut commands, message);
+  }
+
+  turn_state.set(turn.with_phase(TurnPhase::Tumbling));
+}
+
+/// Once the dice stop tumbling, either reveals the bust or lets the player
+/// decide what to do with the hand
+fn settle(
+  mut notice_query: Query<&mut Visibility, With<BustNotice>>,
+  tumble_query: Query<(), With<Tumble>>,
+  turn: Res<State<Turn>>,
+  mut turn_state: ResMut<NextState<Turn>>,
+) {
+  if !tumble_query.is_empty() {
+    return;
+  }
+
+  if let Ok(mut visibility) = notice_query.single_mut() {
+    *visibility = Visibility::Visible;
+
+    turn_state.set(turn.with_phase(TurnPhase::Busted));
+  } else {
+    turn_state.set(turn.with_phase(TurnPhase::Deciding));
+  }
+}
+
+#[expect(clippy::too_many_arguments)]
 fn setup(
   asset_server: Res<AssetServer>,
   mut commands: Commands,
+  cpu_pace: Res<CpuPace>,
   mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+  resume: Option<Res<Resume>>,
+  roster: Res<Roster>,
+  rules: Res<SelectedRules>,
+  mut turn_state: ResMut<NextState<Turn>>,
 ) {
-  commands.spawn(Camera2d).insert(GameElement);
+  commands.spawn(Camera2d);
 
   let texture: Handle<Image> = asset_server.load("die-faces.png");
 
@@ -271,47 +926,157 @@ fn setup(
   let texture_atlas_layout: Handle<TextureAtlasLayout> =
     texture_atlas_layouts.add(layout);
 
-  commands.insert_resource(GameAssets {
+  let assets: GameAssets = GameAssets {
     image: texture,
     layout: texture_atlas_layout,
-  });
+  };
 
-  commands.insert_resource(Scores {
-    cpu: 0,
-    player: 0,
-  });
+  // A resumed game puts its hand back on the table and carries on with
+  // whoever's turn it was
+  if let Some(resume) = resume {
+    let game: PigGame = resume.0.clone();
 
-  commands
-    .insert_resource(HandTimer(Timer::from_seconds(0.5, TimerMode::Repeating)))
+    game.hand().iter().enumerate().for_each(|(position, die)| {
+      hand_die::spawn(
+        &mut commands,
+        &assets,
+        position,
+        *die,
+        roster.players[game.current()].color,
+        0.,
+      );
+    });
+
+    turn_state.set(Turn::start(game.current()));
+
+    commands.insert_resource(CurrentGame(game));
+
+    commands.remove_resource::<Resume>();
+  } else {
+    commands.insert_resource(CurrentGame(rules.new_game(roster.players.len())));
+  }
+
+  commands.insert_resource(assets);
+
+  spawn_hud(&mut commands, &roster);
+
+  commands.insert_resource(HandTimer(Timer::from_seconds(
+    cpu_pace.seconds(),
+    TimerMode::Repeating,
+  )));
 }
 
-fn spawn_die(
-  hand_query: &Query<(Entity, &Sprite), With<HandDie>>,
-  commands: &mut Commands,
-  assets: &GameAssets,
-  new_roll: usize,
-  color: Color,
+fn skip_ahead(
+  mut fast_forward: ResMut<FastForward>,
+  keyboard: Res<ButtonInput<KeyCode>>,
+  settings: Res<GameSettings>,
+) {
+  if let Some(key) = settings.key("skip")
+    && keyboard.just_pressed(key)
+  {
+    fast_forward.0 = true;
+  }
+}
+
+/// Restarts the CPU's timer when a new player's turn begins, so the first
+/// roll waits the full pace rather than whatever was left from the last
+/// turn, and stops any skipping ahead from carrying over
+fn start_turn(
+  cpu_pace: Res<CpuPace>,
+  mut fast_forward: ResMut<FastForward>,
+  mut timer: ResMut<HandTimer>,
+  mut transitions: MessageReader<StateTransitionEvent<Turn>>,
 ) {
-  let rolled_die: f32 = hand_query.iter().count() as f32 * 256.;
+  let started: bool = transitions.read().any(|transition| {
+    transition.entered.is_some_and(|entered| {
+      transition
+        .exited
+        .is_none_or(|exited| exited.player != entered.player)
+    })
+  });
 
-  let mut sprite: Sprite = Sprite::from_atlas_image(
-    assets.image.clone(),
-    TextureAtlas {
-      layout: assets.layout.clone(),
-      index: new_roll - 1,
-    },
-  );
+  if !started {
+    return;
+  }
 
-  sprite.color = color;
+  timer.0 = Timer::from_seconds(cpu_pace.seconds(), TimerMode::Repeating);
 
-  commands.spawn((
-    sprite,
-    Transform::from_xyz(rolled_die - 400., 60., 1.),
-    HandDie,
-    GameElement,
-  ));
+  fast_forward.0 = *cpu_pace == CpuPace::Instant;
 }
 
-fn start_game(mut state: ResMut<NextState<GamePhase>>) {
-  state.set(GamePhase::Player);
+fn toggle_hints(
+  keyboard: Res<ButtonInput<KeyCode>>,
+  settings: Res<GameSettings>,
+  mut show_hints: ResMut<ShowHints>,
+) {
+  if let Some(key) = settings.key("hints")
+    && keyboard.just_pressed(key)
+  {
+    show_hints.0 = !show_hints.0;
+  }
+}
+
+/// The scoreboard in the top right corner, which the update systems fill in
+/// whenever the game changes
+fn spawn_hud(
+  commands: &mut Commands,
+  roster: &Roster,
+) {
+  commands
+    .spawn((
+      Node {
+        flex_direction: FlexDirection::Column,
+        padding: UiRect::all(Val::Px(12.)),
+        position_type: PositionType::Absolute,
+        right: Val::Px(HUD_MARGIN),
+        row_gap: Val::Px(6.),
+        top: Val::Px(HUD_MARGIN),
+        width: Val::Px(HUD_WIDTH),
+        ..default()
+      },
+      BackgroundColor(Color::srgba(0., 0., 0., 0.6)),
+    ))
+    .with_children(|parent| {
+      parent.spawn((
+        Text::default(),
+        TextFont {
+          font_size: 18.,
+          ..default()
+        },
+        TurnReadout,
+      ));
+
+      (0..roster.players.len()).for_each(|player| {
+        parent.spawn((
+          ScoreLabel(player),
+          Text::default(),
+          TextColor::WHITE,
+          TextFont {
+            font_size: 18.,
+            ..default()
+          },
+        ));
+
+        parent
+          .spawn((
+            BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+            Node {
+              height: Val::Px(10.),
+              width: Val::Percent(100.),
+              ..default()
+            },
+          ))
+          .with_children(|track| {
+            track.spawn((
+              BackgroundColor(Color::WHITE),
+              Node {
+                height: Val::Percent(100.),
+                width: Val::Percent(0.),
+                ..default()
+              },
+              ScoreBar(player),
+            ));
+          });
+      });
+    });
 }
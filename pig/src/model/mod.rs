@@ -0,0 +1,7 @@
+pub mod pig_game;
+pub mod roll_odds;
+pub mod roll_outcome;
+pub mod rules;
+pub mod turn_end;
+pub mod turn_record;
+pub mod variant;
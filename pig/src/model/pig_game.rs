@@ -0,0 +1,733 @@
+use super::roll_outcome::RollOutcome;
+use super::turn_end::TurnEnd;
+use super::turn_record::TurnRecord;
+use super::variant::{HOG_MAX_DICE, Variant};
+use ::serde::{Deserialize, Serialize};
+
+pub const DEFAULT_TARGET: usize = 100;
+
+/// The rules of Pig, independent of how the game is shown or played.  Each
+/// turn the current player rolls until they hold, which banks the hand, or
+/// roll a 1, which loses it.  The first to bank the target score wins.  The
+/// variant changes how many dice are rolled and what they score.
+///
+/// Going first is an advantage, so a game can instead finish the round once
+/// someone reaches the target, giving everyone the same number of turns.
+/// The highest score then wins, and players tied for it play tiebreaker
+/// rounds among themselves until one of them is ahead.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PigGame {
+  contenders: Vec<usize>,
+  current: usize,
+  finish_round: bool,
+  hand: Vec<usize>,
+  history: Vec<TurnRecord>,
+  rolls: Vec<Vec<usize>>,
+  scores: Vec<usize>,
+  target: usize,
+  tiebreakers: usize,
+  turn_total: usize,
+  turns: Vec<usize>,
+  variant: Variant,
+  winner: Option<usize>,
+}
+
+impl PigGame {
+  /// # Panics
+  ///
+  /// If there are fewer than two players
+  pub fn new(
+    players: usize,
+    target: usize,
+  ) -> Self {
+    assert!(players >= 2, "Pig needs at least two players");
+
+    Self {
+      contenders: (0..players).collect(),
+      current: 0,
+      finish_round: false,
+      hand: Vec::new(),
+      history: Vec::new(),
+      rolls: Vec::new(),
+      scores: vec![0; players],
+      target,
+      tiebreakers: 0,
+      turn_total: 0,
+      turns: vec![0; players],
+      variant: Variant::Standard,
+      winner: None,
+    }
+  }
+
+  /// Plays on to the end of the round once someone reaches the target
+  pub fn with_finish_round(
+    mut self,
+    finish_round: bool,
+  ) -> Self {
+    self.finish_round = finish_round;
+
+    self
+  }
+
+  pub fn with_variant(
+    mut self,
+    variant: Variant,
+  ) -> Self {
+    self.variant = variant;
+
+    self
+  }
+
+  /// The highest score among the current player's opponents
+  pub fn best_opponent_score(&self) -> usize {
+    self
+      .scores
+      .iter()
+      .enumerate()
+      .filter(|(player, _)| *player != self.current)
+      .map(|(_, score)| *score)
+      .max()
+      .unwrap_or_default()
+  }
+
+  /// The players who can still win, which is everyone until a tie sends the
+  /// leaders to a tiebreaker
+  pub fn contenders(&self) -> &[usize] {
+    &self.contenders
+  }
+
+  /// The player whose turn it is
+  pub fn current(&self) -> usize {
+    self.current
+  }
+
+  /// The dice rolled so far this turn
+  pub fn hand(&self) -> &[usize] {
+    &self.hand
+  }
+
+  /// Every finished turn, oldest first
+  pub fn history(&self) -> &[TurnRecord] {
+    &self.history
+  }
+
+  /// Banks the hand for the current player and passes the turn on.  Returns
+  /// the winner if that ended the game.
+  ///
+  /// # Panics
+  ///
+  /// If the game is already over
+  pub fn hold(&mut self) -> Option<usize> {
+    assert!(self.winner.is_none(), "The game is over");
+
+    let banked: usize = self.turn_total;
+
+    self.scores[self.current] += banked;
+
+    self.clear_hand();
+
+    if !self.finish_round && self.scores[self.current] >= self.target {
+      self.record(TurnEnd::Banked(banked));
+
+      self.winner = Some(self.current);
+    } else {
+      self.pass(TurnEnd::Banked(banked));
+    }
+
+    self.winner
+  }
+
+  /// True once the game is in its last round, when playing to the end of
+  /// the round, or in a tiebreaker
+  pub fn is_final_round(&self) -> bool {
+    self.finish_round
+      && (self.tiebreakers > 0
+        || self.scores.iter().any(|score| *score >= self.target))
+  }
+
+  /// True while the players tied for the lead play another round
+  pub fn is_tiebreaker(&self) -> bool {
+    self.tiebreakers > 0
+  }
+
+  /// True if the game holds together: every player it names is seated and
+  /// every die shows a face.  A game that arrives from elsewhere, such as
+  /// over the network, should be checked before it is played or shown.
+  pub fn is_valid(&self) -> bool {
+    let players: usize = self.scores.len();
+
+    let is_seated = |player: &usize| *player < players;
+
+    let is_roll = |roll: &Vec<usize>| {
+      roll.len() <= HOG_MAX_DICE.max(2)
+        && roll.iter().all(|die| (1..=6).contains(die))
+    };
+
+    players >= 2
+      && self.turns.len() == players
+      && is_seated(&self.current)
+      && !self.contenders.is_empty()
+      && self.contenders.iter().all(is_seated)
+      && self.winner.iter().all(is_seated)
+      && self.hand.iter().all(|die| (1..=6).contains(die))
+      && self.rolls.iter().all(is_roll)
+      && self.history.iter().all(|record| {
+        is_seated(&record.player) && record.rolls.iter().all(is_roll)
+      })
+  }
+
+  /// True once a Hog player has rolled, since they only get one roll
+  pub fn must_hold(&self) -> bool {
+    self.variant == Variant::Hog && !self.hand.is_empty()
+  }
+
+  /// Adds the dice to the hand, or loses the hand if the variant says so
+  ///
+  /// # Panics
+  ///
+  /// If the game is over, a die is not between 1 and 6, or the number of
+  /// dice is wrong for the variant
+  pub fn roll(
+    &mut self,
+    dice: &[usize],
+  ) -> RollOutcome {
+    assert!(self.winner.is_none(), "The game is over");
+
+    assert!(!self.must_hold(), "A Hog player only rolls once");
+
+    assert!(
+      dice.iter().all(|die| (1..=6).contains(die)),
+      "A die shows 1 to 6, not {dice:?}"
+    );
+
+    match self.variant.dice() {
+      Some(count) => assert_eq!(dice.len(), count, "{:?}", self.variant),
+      None => assert!((1..=HOG_MAX_DICE).contains(&dice.len())),
+    }
+
+    self.rolls.push(dice.to_vec());
+
+    if self.variant.loses_score(dice) {
+      self.scores[self.current] = 0;
+
+      self.clear_hand();
+
+      self.pass(TurnEnd::LostScore);
+
+      return RollOutcome::LostScore;
+    }
+
+    let Some(points) = self.variant.points(dice) else {
+      self.clear_hand();
+
+      self.pass(TurnEnd::PiggedOut);
+
+      return RollOutcome::PiggedOut;
+    };
+
+    self.hand.extend_from_slice(dice);
+
+    self.turn_total += points;
+
+    RollOutcome::Added(self.turn_total)
+  }
+
+  /// The dice of each roll so far this turn
+  pub fn rolls(&self) -> &[Vec<usize>] {
+    &self.rolls
+  }
+
+  pub fn score(
+    &self,
+    player: usize,
+  ) -> usize {
+    self.scores[player]
+  }
+
+  pub fn scores(&self) -> &[usize] {
+    &self.scores
+  }
+
+  pub fn target(&self) -> usize {
+    self.target
+  }
+
+  /// The number of turns the player has finished
+  pub fn turns_taken(
+    &self,
+    player: usize,
+  ) -> usize {
+    self.turns[player]
+  }
+
+  /// What the hand is worth if the player holds
+  pub fn turn_total(&self) -> usize {
+    self.turn_total
+  }
+
+  pub fn variant(&self) -> Variant {
+    self.variant
+  }
+
+  /// The player who won, once the game is over
+  pub fn winner(&self) -> Option<usize> {
+    self.winner
+  }
+
+  fn clear_hand(&mut self) {
+    self.hand.clear();
+
+    self.turn_total = 0;
+  }
+
+  /// Adds the current player's turn to the history
+  fn record(
+    &mut self,
+    end: TurnEnd,
+  ) {
+    self.turns[self.current] += 1;
+
+    self.history.push(TurnRecord {
+      end,
+      player: self.current,
+      rolls: ::std::mem::take(&mut self.rolls),
+      score: self.scores[self.current],
+    });
+  }
+
+  /// Decides the game at the end of a round, if it is being finished
+  fn end_round(&mut self) {
+    if !self.is_final_round() {
+      return;
+    }
+
+    let best: usize = self
+      .contenders
+      .iter()
+      .map(|player| self.scores[*player])
+      .max()
+      .unwrap_or_default();
+
+    let leaders: Vec<usize> = self
+      .contenders
+      .iter()
+      .copied()
+      .filter(|player| self.scores[*player] == best)
+      .collect();
+
+    if let [winner] = leaders[..] {
+      self.winner = Some(winner);
+    } else {
+      self.contenders = leaders;
+
+      self.tiebreakers += 1;
+    }
+  }
+
+  /// Ends the turn, and any game decided by the end of the round.  Only the
+  /// contenders get another turn.
+  fn pass(
+    &mut self,
+    end: TurnEnd,
+  ) {
+    self.record(end);
+
+    let current: usize = self.current;
+
+    if let Some(next) = self.contenders.iter().find(|player| **player > current)
+    {
+      self.current = *next;
+
+      return;
+    }
+
+    self.end_round();
+
+    if self.winner.is_none() {
+      self.current = self.contenders[0];
+    }
+  }
+}
+
+impl Default for PigGame {
+  fn default() -> Self {
+    Self::new(2, DEFAULT_TARGET)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_new_game() {
+    let game: PigGame = PigGame::new(3, 50);
+
+    assert_eq!(game.current(), 0);
+    assert_eq!(
+      game.scores(),
+      &[
+        0, 0, 0
+      ]
+    );
+    assert_eq!(game.target(), 50);
+    assert_eq!(game.turn_total(), 0);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_one_player() {
+    PigGame::new(1, DEFAULT_TARGET);
+  }
+
+  #[test]
+  fn test_roll_adds_to_hand() {
+    let mut game: PigGame = PigGame::default();
+
+    assert_eq!(game.roll(&[4]), RollOutcome::Added(4));
+    assert_eq!(game.roll(&[6]), RollOutcome::Added(10));
+    assert_eq!(
+      game.hand(),
+      &[
+        4, 6
+      ]
+    );
+    assert_eq!(game.current(), 0);
+  }
+
+  #[test]
+  fn test_rolling_one_loses_hand() {
+    let mut game: PigGame = PigGame::default();
+
+    game.roll(&[5]);
+
+    assert_eq!(game.roll(&[1]), RollOutcome::PiggedOut);
+    assert_eq!(game.turn_total(), 0);
+    assert_eq!(game.score(0), 0);
+    assert_eq!(game.current(), 1);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_roll_out_of_range() {
+    PigGame::default().roll(&[7]);
+  }
+
+  #[test]
+  fn test_hold_banks_hand() {
+    let mut game: PigGame = PigGame::default();
+
+    game.roll(&[3]);
+    game.roll(&[2]);
+
+    assert_eq!(game.hold(), None);
+    assert_eq!(
+      game.scores(),
+      &[
+        5, 0
+      ]
+    );
+    assert_eq!(game.hand(), &[] as &[usize]);
+    assert_eq!(game.current(), 1);
+  }
+
+  #[test]
+  fn test_turns_wrap_around() {
+    let mut game: PigGame = PigGame::new(3, DEFAULT_TARGET);
+
+    game.hold();
+    game.roll(&[1]);
+
+    assert_eq!(game.current(), 2);
+
+    game.hold();
+
+    assert_eq!(game.current(), 0);
+    assert_eq!(game.turns_taken(0), 1);
+    assert_eq!(game.turns_taken(2), 1);
+  }
+
+  #[test]
+  fn test_reaching_target_wins() {
+    let mut game: PigGame = PigGame::new(2, 10);
+
+    game.roll(&[6]);
+
+    assert_eq!(game.hold(), None);
+
+    game.roll(&[2]);
+    game.hold();
+
+    game.roll(&[4]);
+
+    assert_eq!(game.hold(), Some(0));
+    assert_eq!(game.current(), 0);
+    assert_eq!(
+      game.scores(),
+      &[
+        10, 2
+      ]
+    );
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_no_moves_after_game_over() {
+    let mut game: PigGame = PigGame::new(2, 5);
+
+    game.roll(&[6]);
+    game.hold();
+    game.roll(&[2]);
+  }
+
+  #[test]
+  fn test_two_dice() {
+    let mut game: PigGame = PigGame::default().with_variant(Variant::TwoDice);
+
+    assert_eq!(
+      game.roll(&[
+        3, 4
+      ]),
+      RollOutcome::Added(7)
+    );
+
+    game.hold();
+    game.hold();
+
+    assert_eq!(
+      game.roll(&[
+        2, 2
+      ]),
+      RollOutcome::Added(4)
+    );
+    assert_eq!(
+      game.roll(&[
+        1, 5
+      ]),
+      RollOutcome::PiggedOut
+    );
+    assert_eq!(
+      game.scores(),
+      &[
+        7, 0
+      ]
+    );
+
+    game.hold();
+
+    assert_eq!(
+      game.roll(&[
+        1, 1
+      ]),
+      RollOutcome::LostScore
+    );
+    assert_eq!(
+      game.scores(),
+      &[
+        0, 0
+      ]
+    );
+    assert_eq!(game.current(), 1);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_two_dice_needs_two() {
+    PigGame::default().with_variant(Variant::TwoDice).roll(&[3]);
+  }
+
+  #[test]
+  fn test_big_pig() {
+    let mut game: PigGame = PigGame::default().with_variant(Variant::BigPig);
+
+    assert_eq!(
+      game.roll(&[
+        3, 3
+      ]),
+      RollOutcome::Added(12)
+    );
+    assert_eq!(
+      game.roll(&[
+        1, 1
+      ]),
+      RollOutcome::Added(37)
+    );
+    assert_eq!(
+      game.roll(&[
+        2, 5
+      ]),
+      RollOutcome::Added(44)
+    );
+    assert_eq!(
+      game.hand(),
+      &[
+        3, 3, 1, 1, 2, 5
+      ]
+    );
+    assert_eq!(
+      game.roll(&[
+        6, 1
+      ]),
+      RollOutcome::PiggedOut
+    );
+    assert_eq!(
+      game.scores(),
+      &[
+        0, 0
+      ]
+    );
+  }
+
+  #[test]
+  fn test_hog() {
+    let mut game: PigGame = PigGame::default().with_variant(Variant::Hog);
+
+    assert!(!game.must_hold());
+    assert_eq!(
+      game.roll(&[
+        2, 3, 4, 5, 6
+      ]),
+      RollOutcome::Added(20)
+    );
+    assert!(game.must_hold());
+    assert_eq!(game.hold(), None);
+    assert_eq!(
+      game.roll(&[
+        6, 6, 1
+      ]),
+      RollOutcome::PiggedOut
+    );
+    assert_eq!(
+      game.scores(),
+      &[
+        20, 0
+      ]
+    );
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_hog_rolls_once() {
+    let mut game: PigGame = PigGame::default().with_variant(Variant::Hog);
+
+    game.roll(&[2]);
+    game.roll(&[2]);
+  }
+
+  #[test]
+  fn test_finish_round() {
+    let mut game: PigGame = PigGame::new(3, 10).with_finish_round(true);
+
+    game.roll(&[6]);
+    game.roll(&[6]);
+
+    assert_eq!(game.hold(), None);
+    assert!(game.is_final_round());
+
+    game.roll(&[5]);
+    game.roll(&[6]);
+
+    assert_eq!(game.hold(), None);
+
+    game.roll(&[1]);
+
+    assert_eq!(game.winner(), Some(0));
+    assert_eq!(game.turns_taken(2), 1);
+  }
+
+  #[test]
+  fn test_tiebreaker() {
+    let mut game: PigGame = PigGame::new(3, 10).with_finish_round(true);
+
+    game.roll(&[4]);
+    game.roll(&[6]);
+    game.hold();
+    game.hold();
+    game.roll(&[5]);
+    game.roll(&[5]);
+
+    assert_eq!(game.hold(), None);
+    assert!(game.is_tiebreaker());
+    assert_eq!(
+      game.contenders(),
+      &[
+        0, 2
+      ]
+    );
+    assert_eq!(game.current(), 0);
+
+    game.roll(&[3]);
+    game.hold();
+
+    assert_eq!(game.current(), 2);
+
+    game.roll(&[4]);
+
+    assert_eq!(game.hold(), Some(2));
+    assert_eq!(
+      game.scores(),
+      &[
+        13, 0, 14
+      ]
+    );
+  }
+
+  #[test]
+  fn test_history() {
+    let mut game: PigGame = PigGame::default();
+
+    game.roll(&[4]);
+    game.roll(&[6]);
+    game.hold();
+    game.roll(&[1]);
+
+    assert_eq!(
+      game.history(),
+      &[
+        TurnRecord {
+          end: TurnEnd::Banked(10),
+          player: 0,
+          rolls: vec![
+            vec![4],
+            vec![6],
+          ],
+          score: 10,
+        },
+        TurnRecord {
+          end: TurnEnd::PiggedOut,
+          player: 1,
+          rolls: vec![vec![1]],
+          score: 0,
+        },
+      ]
+    );
+    assert!(game.rolls().is_empty());
+  }
+
+  #[test]
+  fn test_is_valid() {
+    let mut game: PigGame = PigGame::new(2, 20);
+
+    game.roll(&[4]);
+
+    assert!(game.is_valid());
+
+    let mut seated_elsewhere: PigGame = game.clone();
+
+    seated_elsewhere.current = 2;
+
+    assert!(!seated_elsewhere.is_valid());
+
+    let mut blank_die: PigGame = game.clone();
+
+    blank_die.hand.push(0);
+
+    assert!(!blank_die.is_valid());
+
+    let mut lone: PigGame = game;
+
+    lone.scores.pop();
+
+    assert!(!lone.is_valid());
+  }
+}
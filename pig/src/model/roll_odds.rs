@@ -0,0 +1,120 @@
+use super::pig_game::PigGame;
+
+/// The most dice whose every outcome is worth listing.  Hog can roll more,
+/// but there any 1 loses and the other faces average 4.
+const MAX_LISTED_DICE: usize = 2;
+
+/// What rolling again is likely to do for the current player
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RollOdds {
+  /// The chance of losing the hand
+  pub bust: f64,
+  /// The average change to the player's banked score plus hand
+  pub expected_gain: f64,
+}
+
+impl RollOdds {
+  pub fn new(
+    game: &PigGame,
+    dice: usize,
+  ) -> Self {
+    let hand: f64 = game.turn_total() as f64;
+
+    if dice > MAX_LISTED_DICE {
+      let safe: f64 = (5. / 6_f64).powi(dice as i32);
+
+      return Self {
+        bust: 1. - safe,
+        expected_gain: safe * 4. * dice as f64 - (1. - safe) * hand,
+      };
+    }
+
+    let score: f64 = game.score(game.current()) as f64;
+
+    let outcomes: usize = 6_usize.pow(dice as u32);
+
+    let mut busts: usize = 0;
+
+    let mut gain: f64 = 0.;
+
+    (0..outcomes).for_each(|outcome| {
+      let faces: Vec<usize> = (0..dice)
+        .map(|die| outcome / 6_usize.pow(die as u32) % 6 + 1)
+        .collect();
+
+      if game.variant().loses_score(&faces) {
+        busts += 1;
+
+        gain -= hand + score;
+      } else if let Some(points) = game.variant().points(&faces) {
+        gain += points as f64;
+      } else {
+        busts += 1;
+
+        gain -= hand;
+      }
+    });
+
+    Self {
+      bust: busts as f64 / outcomes as f64,
+      expected_gain: gain / outcomes as f64,
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::super::variant::Variant;
+  use super::*;
+
+  fn assert_near(
+    actual: f64,
+    expected: f64,
+  ) {
+    assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+  }
+
+  #[test]
+  fn test_standard() {
+    let mut game: PigGame = PigGame::default();
+
+    let odds: RollOdds = RollOdds::new(&game, 1);
+
+    assert_near(odds.bust, 1. / 6.);
+    assert_near(odds.expected_gain, 20. / 6.);
+
+    // Past a hand of 20 another roll loses more than it adds on average
+    game.roll(&[6]);
+    game.roll(&[6]);
+    game.roll(&[6]);
+    game.roll(&[3]);
+
+    assert!(RollOdds::new(&game, 1).expected_gain < 0.);
+  }
+
+  #[test]
+  fn test_two_dice_counts_the_score_at_risk() {
+    let mut game: PigGame = PigGame::default().with_variant(Variant::TwoDice);
+
+    game.roll(&[
+      5, 5,
+    ]);
+    game.hold();
+    game.hold();
+
+    let odds: RollOdds = RollOdds::new(&game, 2);
+
+    assert_near(odds.bust, 11. / 36.);
+    assert_near(odds.expected_gain, (25. * 8. - 10.) / 36.);
+  }
+
+  #[test]
+  fn test_hog_many_dice() {
+    let game: PigGame = PigGame::default().with_variant(Variant::Hog);
+
+    let odds: RollOdds = RollOdds::new(&game, 5);
+
+    assert_near(odds.bust, 1. - (5. / 6_f64).powi(5));
+    assert_near(odds.expected_gain, 20. * (5. / 6_f64).powi(5));
+  }
+}
@@ -0,0 +1,10 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RollOutcome {
+  /// The dice were added to the hand, which is now worth this much
+  Added(usize),
+  /// Double 1s in Two-Dice Pig, so the player's score is gone as well as
+  /// the hand, and the turn passes on
+  LostScore,
+  /// A 1 was rolled, so the hand is lost and the turn passes on
+  PiggedOut,
+}
@@ -0,0 +1,33 @@
+use super::pig_game::{DEFAULT_TARGET, PigGame};
+use super::variant::Variant;
+use ::serde::{Deserialize, Serialize};
+
+/// What a game is played to, under which variant, and whether the round is
+/// finished once someone reaches the target
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Rules {
+  pub finish_round: bool,
+  pub target: usize,
+  pub variant: Variant,
+}
+
+impl Rules {
+  pub fn new_game(
+    &self,
+    players: usize,
+  ) -> PigGame {
+    PigGame::new(players, self.target)
+      .with_finish_round(self.finish_round)
+      .with_variant(self.variant)
+  }
+}
+
+impl Default for Rules {
+  fn default() -> Self {
+    Self {
+      finish_round: false,
+      target: DEFAULT_TARGET,
+      variant: Variant::default(),
+    }
+  }
+}
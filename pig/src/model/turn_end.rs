@@ -0,0 +1,12 @@
+use ::serde::{Deserialize, Serialize};
+
+/// How a turn finished
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum TurnEnd {
+  /// The player held and banked this many points
+  Banked(usize),
+  /// Double 1s in Two-Dice Pig took the player's whole score
+  LostScore,
+  /// A 1 lost the hand
+  PiggedOut,
+}
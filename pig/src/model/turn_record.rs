@@ -0,0 +1,71 @@
+use super::turn_end::TurnEnd;
+use ::serde::{Deserialize, Serialize};
+
+/// One finished turn in a game's history
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TurnRecord {
+  pub end: TurnEnd,
+  pub player: usize,
+  /// The dice of each roll, in order
+  pub rolls: Vec<Vec<usize>>,
+  /// The player's score once the turn was over
+  pub score: usize,
+}
+
+impl TurnRecord {
+  /// A line for the game log, such as "Alice rolled 4, 6 and held for 10"
+  pub fn describe(
+    &self,
+    name: &str,
+  ) -> String {
+    let rolls: String = self
+      .rolls
+      .iter()
+      .map(|dice| {
+        dice
+          .iter()
+          .map(|die| die.to_string())
+          .collect::<Vec<String>>()
+          .join("+")
+      })
+      .collect::<Vec<String>>()
+      .join(", ");
+
+    let ending: String = match self.end {
+      TurnEnd::Banked(points) => format!("held for {points}"),
+      TurnEnd::LostScore => "lost everything to snake eyes".into(),
+      TurnEnd::PiggedOut => "pigged out".into(),
+    };
+
+    if rolls.is_empty() {
+      format!("{name} {ending} (total {})", self.score)
+    } else {
+      format!("{name} rolled {rolls} and {ending} (total {})", self.score)
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_describe() {
+    let record: TurnRecord = TurnRecord {
+      end: TurnEnd::Banked(13),
+      player: 0,
+      rolls: vec![
+        vec![
+          4, 3,
+        ],
+        vec![6],
+      ],
+      score: 40,
+    };
+
+    assert_eq!(
+      record.describe("Alice"),
+      "Alice rolled 4+3, 6 and held for 13 (total 40)"
+    );
+  }
+}
@@ -0,0 +1,126 @@
+use ::serde::{Deserialize, Serialize};
+use ::std::str::FromStr;
+
+/// How many dice a Hog player rolls unless they choose otherwise, which is
+/// about the most a single roll is worth on average
+pub const HOG_DEFAULT_DICE: usize = 5;
+
+/// The most dice a Hog player may roll at once
+pub const HOG_MAX_DICE: usize = 10;
+
+/// What double 1s are worth in Big Pig
+pub const SNAKE_EYES_BONUS: usize = 25;
+
+/// The rule sets PigGame can play
+#[derive(
+  Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize,
+)]
+pub enum Variant {
+  /// Two dice a roll, doubles count twice and double 1s are worth 25, but a
+  /// single 1 loses the hand
+  BigPig,
+  /// One roll a turn of as many dice as the player likes, which scores
+  /// nothing if any of them is a 1
+  Hog,
+  /// One die a roll, and a 1 loses the hand
+  #[default]
+  Standard,
+  /// Two dice a roll, where a single 1 loses the hand and double 1s lose the
+  /// player's whole score
+  TwoDice,
+}
+
+impl Variant {
+  pub fn all() -> [Self; 4] {
+    [
+      Self::Standard,
+      Self::TwoDice,
+      Self::BigPig,
+      Self::Hog,
+    ]
+  }
+
+  /// How many dice every roll uses, or None if the player chooses
+  pub fn dice(&self) -> Option<usize> {
+    match self {
+      Self::BigPig | Self::TwoDice => Some(2),
+      Self::Hog => None,
+      Self::Standard => Some(1),
+    }
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      Self::BigPig => "Big Pig",
+      Self::Hog => "Hog",
+      Self::Standard => "Pig",
+      Self::TwoDice => "Two-Dice Pig",
+    }
+  }
+
+  /// True if the dice take the player's banked score as well as the hand
+  pub fn loses_score(
+    &self,
+    dice: &[usize],
+  ) -> bool {
+    *self == Self::TwoDice
+      && dice
+        == [
+          1, 1,
+        ]
+  }
+
+  /// A short name for the command line, such as "two-dice"
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::BigPig => "big-pig",
+      Self::Hog => "hog",
+      Self::Standard => "pig",
+      Self::TwoDice => "two-dice",
+    }
+  }
+
+  /// What the dice add to the hand, or None if they lose it
+  pub fn points(
+    &self,
+    dice: &[usize],
+  ) -> Option<usize> {
+    let ones: usize = dice.iter().filter(|die| **die == 1).count();
+
+    let sum: usize = dice.iter().sum();
+
+    let doubles: bool = dice.len() == 2 && dice[0] == dice[1];
+
+    match self {
+      Self::BigPig if ones == 2 => Some(SNAKE_EYES_BONUS),
+      Self::BigPig if doubles && ones == 0 => Some(sum * 2),
+      _ if ones > 0 => None,
+      _ => Some(sum),
+    }
+  }
+}
+
+impl FromStr for Variant {
+  type Err = String;
+
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    Self::all()
+      .into_iter()
+      .find(|variant| variant.name() == name)
+      .ok_or_else(|| format!("Unknown variant: {name}"))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_names_round_trip() {
+    Variant::all().into_iter().for_each(|variant| {
+      assert_eq!(variant.name().parse::<Variant>(), Ok(variant));
+    });
+
+    assert!("triple-pig".parse::<Variant>().is_err());
+  }
+}
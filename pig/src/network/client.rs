@@ -0,0 +1,335 @@
+use super::client_message::ClientMessage;
+use super::connecting::Connecting;
+use super::connection::{Connection, PROTOCOL_VERSION};
+use super::host_message::HostMessage;
+use ::std::net::SocketAddr;
+use ::std::time::{Duration, Instant};
+
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A player's side of a game hosted elsewhere.  It reconnects on its own
+/// after the connection drops, and reclaims its seat with the session the
+/// host gave it.
+#[derive(Debug)]
+pub struct Client {
+  address: SocketAddr,
+  connecting: Option<Connecting>,
+  connection: Option<Connection>,
+  last_attempt: Option<Instant>,
+  name: String,
+  rejected: Option<String>,
+  seat: Option<usize>,
+  session: Option<u64>,
+}
+
+impl Client {
+  pub fn new(
+    address: SocketAddr,
+    name: String,
+  ) -> Self {
+    Self {
+      address,
+      connecting: None,
+      connection: None,
+      last_attempt: None,
+      name,
+      rejected: None,
+      seat: None,
+      session: None,
+    }
+  }
+
+  /// Hangs up and stops trying to reconnect, for a host that cannot be
+  /// played with
+  pub fn abandon(
+    &mut self,
+    reason: String,
+  ) {
+    self.connecting = None;
+
+    self.connection = None;
+
+    self.rejected = Some(reason);
+  }
+
+  pub fn address(&self) -> SocketAddr {
+    self.address
+  }
+
+  pub fn is_connected(&self) -> bool {
+    self.connection.is_some()
+  }
+
+  /// Connects if it is time to try again, and returns what the host sent.
+  /// Welcome and Rejected are also kept for seat and rejected.
+  pub fn poll(&mut self) -> Vec<HostMessage> {
+    if self.rejected.is_some() {
+      return Vec::new();
+    }
+
+    if self.connection.is_none() {
+      self.reconnect();
+    }
+
+    let Some(connection) = &mut self.connection else {
+      return Vec::new();
+    };
+
+    let messages: Vec<HostMessage> = match connection.receive() {
+      Ok(messages) => messages,
+      Err(_) => {
+        self.connection = None;
+
+        return Vec::new();
+      },
+    };
+
+    messages.iter().for_each(|message| match message {
+      HostMessage::Rejected {
+        reason,
+      } => {
+        self.rejected = Some(reason.clone());
+
+        self.connection = None;
+      },
+      HostMessage::Welcome {
+        seat,
+        session,
+      } => {
+        self.seat = Some(*seat);
+
+        self.session = Some(*session);
+      },
+      HostMessage::Game {
+        ..
+      } => {},
+    });
+
+    messages
+  }
+
+  /// Why the host turned the player away, or the player gave up on it
+  pub fn rejected(&self) -> Option<&str> {
+    self.rejected.as_deref()
+  }
+
+  pub fn seat(&self) -> Option<usize> {
+    self.seat
+  }
+
+  /// Sends the request unless the connection is down, in which case the
+  /// player asks again once it is back
+  pub fn send(
+    &mut self,
+    message: &ClientMessage,
+  ) -> bool {
+    let Some(connection) = &mut self.connection else {
+      return false;
+    };
+
+    if connection.send(message).is_err() {
+      self.connection = None;
+
+      return false;
+    }
+
+    true
+  }
+
+  /// Starts connecting if it is time to try again, and says Hello once the
+  /// connection is made
+  fn reconnect(&mut self) {
+    let Some(connecting) = &mut self.connecting else {
+      if self
+        .last_attempt
+        .is_none_or(|last_attempt| last_attempt.elapsed() >= RETRY_INTERVAL)
+      {
+        self.last_attempt = Some(Instant::now());
+
+        self.connecting = Some(Connecting::new(self.address));
+      }
+
+      return;
+    };
+
+    let Some(result) = connecting.poll() else {
+      return;
+    };
+
+    self.connecting = None;
+
+    let Ok(mut connection) = result else {
+      return;
+    };
+
+    let hello: ClientMessage = ClientMessage::Hello {
+      name: self.name.clone(),
+      session: self.session,
+      version: PROTOCOL_VERSION,
+    };
+
+    if connection.send(&hello).is_ok() {
+      self.connection = Some(connection);
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::super::host::Host;
+  use super::*;
+  use ::std::io::Write;
+  use ::std::net::TcpStream;
+  use ::std::thread;
+
+  /// Polls both ends until the condition holds, or fails after a while
+  fn settle(
+    host: &mut Host,
+    client: &mut Client,
+    mut condition: impl FnMut(&mut Host, &Client) -> bool,
+  ) {
+    let deadline: Instant = Instant::now() + Duration::from_secs(5);
+
+    while !condition(host, client) {
+      assert!(Instant::now() < deadline, "The network did not settle");
+
+      host.poll();
+
+      client.poll();
+
+      thread::sleep(Duration::from_millis(2));
+    }
+  }
+
+  fn localhost() -> Host {
+    Host::bind("127.0.0.1:0".parse().unwrap()).unwrap()
+  }
+
+  #[test]
+  fn test_join_and_play() {
+    let mut host: Host = localhost();
+
+    host.set_remote_seats(&[
+      false, true,
+    ]);
+
+    let mut client: Client =
+      Client::new(host.local_addr().unwrap(), "Remy".into());
+
+    settle(&mut host, &mut client, |_, client| client.seat().is_some());
+
+    assert!(host.take_joined());
+    assert_eq!(client.seat(), Some(1));
+    assert_eq!(host.seat(1).unwrap().name.as_deref(), Some("Remy"));
+    assert!(client.send(&ClientMessage::Roll {
+      dice: 1
+    }));
+
+    settle(&mut host, &mut client, |host, _| {
+      host.seat(1).is_some_and(|seat| !seat.inbox.is_empty())
+    });
+
+    assert_eq!(
+      host.take(1),
+      Some(ClientMessage::Roll {
+        dice: 1
+      })
+    );
+    assert_eq!(host.take(1), None);
+  }
+
+  #[test]
+  fn test_messages_after_hello_are_kept() {
+    let mut host: Host = localhost();
+
+    host.set_remote_seats(&[
+      false, true,
+    ]);
+
+    let mut stream: TcpStream =
+      TcpStream::connect(host.local_addr().unwrap()).unwrap();
+
+    let mut bytes: Vec<u8> = Vec::new();
+
+    [
+      ClientMessage::Hello {
+        name: "Remy".into(),
+        session: None,
+        version: PROTOCOL_VERSION,
+      },
+      ClientMessage::Hold,
+    ]
+    .iter()
+    .for_each(|message| {
+      ::serde_json::to_writer(&mut bytes, message).unwrap();
+
+      bytes.push(b'\n');
+    });
+
+    stream.write_all(&bytes).unwrap();
+
+    let deadline: Instant = Instant::now() + Duration::from_secs(5);
+
+    while !host.seat(1).is_some_and(|seat| seat.is_connected()) {
+      assert!(Instant::now() < deadline, "The host never seated Remy");
+
+      host.poll();
+
+      thread::sleep(Duration::from_millis(2));
+    }
+
+    assert_eq!(host.take(1), Some(ClientMessage::Hold));
+  }
+
+  #[test]
+  fn test_reconnect_reclaims_the_seat() {
+    let mut host: Host = localhost();
+
+    host.set_remote_seats(&[
+      false, true, true,
+    ]);
+
+    let mut client: Client =
+      Client::new(host.local_addr().unwrap(), "Remy".into());
+
+    let mut other: Client =
+      Client::new(host.local_addr().unwrap(), "Otto".into());
+
+    settle(&mut host, &mut client, |_, client| client.seat().is_some());
+
+    settle(&mut host, &mut other, |_, other| other.seat().is_some());
+
+    host.take_joined();
+
+    // The table is now full, so only the session gets the seat back
+    client.connection = None;
+
+    client.last_attempt = None;
+
+    settle(&mut host, &mut client, |host, _| host.take_joined());
+
+    assert_eq!(client.seat(), Some(1));
+    assert_eq!(other.seat(), Some(2));
+    assert!(client.is_connected());
+    assert_eq!(host.seat(1).unwrap().name.as_deref(), Some("Remy"));
+  }
+
+  #[test]
+  fn test_full_table_rejects() {
+    let mut host: Host = localhost();
+
+    host.set_remote_seats(&[
+      false, false,
+    ]);
+
+    let mut client: Client =
+      Client::new(host.local_addr().unwrap(), "Remy".into());
+
+    settle(&mut host, &mut client, |_, client| {
+      client.rejected().is_some()
+    });
+
+    assert_eq!(client.rejected(), Some("The table is full"));
+    assert!(!client.is_connected());
+  }
+}
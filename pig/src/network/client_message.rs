@@ -0,0 +1,19 @@
+use ::serde::{Deserialize, Serialize};
+
+/// What a joining player sends to the host
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ClientMessage {
+  /// The first message on every connection.  A session from an earlier
+  /// Welcome reclaims that seat after a dropped connection.
+  Hello {
+    name: String,
+    session: Option<u64>,
+    version: u32,
+  },
+  /// Bank the hand
+  Hold,
+  /// Roll again, with this many dice if the variant lets the player choose
+  Roll {
+    dice: usize,
+  },
+}
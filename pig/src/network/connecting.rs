@@ -0,0 +1,40 @@
+use super::connection::Connection;
+use ::std::io;
+use ::std::net::{SocketAddr, TcpStream};
+use ::std::thread::{self, JoinHandle};
+use ::std::time::Duration;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A connection being made on a thread of its own, so that the game can go
+/// on drawing frames while it waits for the other end to answer
+#[derive(Debug)]
+pub struct Connecting {
+  handle: Option<JoinHandle<io::Result<TcpStream>>>,
+}
+
+impl Connecting {
+  pub fn new(address: SocketAddr) -> Self {
+    Self {
+      handle: Some(thread::spawn(move || {
+        TcpStream::connect_timeout(&address, CONNECT_TIMEOUT)
+      })),
+    }
+  }
+
+  /// The connection once it is made or has failed, or None while it is
+  /// still being made
+  pub fn poll(&mut self) -> Option<io::Result<Connection>> {
+    if !self.handle.as_ref()?.is_finished() {
+      return None;
+    }
+
+    let stream: io::Result<TcpStream> = self
+      .handle
+      .take()?
+      .join()
+      .unwrap_or_else(|_| Err(io::ErrorKind::NotConnected.into()));
+
+    Some(stream.and_then(Connection::new))
+  }
+}
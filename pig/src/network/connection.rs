@@ -0,0 +1,239 @@
+use ::serde::Serialize;
+use ::serde::de::DeserializeOwned;
+use ::std::io::{self, ErrorKind, Read, Write};
+use ::std::net::TcpStream;
+
+pub const DEFAULT_PORT: u16 = 7878;
+
+/// The longest line a peer may send, well beyond the largest game, so that
+/// a peer that never ends a line cannot make this side buffer forever
+pub const MAX_LINE_BYTES: usize = 1 << 20;
+
+/// How much may wait to be sent before a peer that is not reading is given
+/// up on
+pub const MAX_QUEUED_BYTES: usize = 4 << 20;
+
+/// Bumped whenever a message changes shape, so that mismatched builds refuse
+/// to play rather than misread each other
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// A non-blocking TCP stream carrying one JSON message per line.  Nothing
+/// here waits on the network, so a game can poll it every frame.  Making
+/// the connection in the first place is left to Connecting.
+#[derive(Debug)]
+pub struct Connection {
+  closed: bool,
+  incoming: Vec<u8>,
+  outgoing: Vec<u8>,
+  stream: TcpStream,
+}
+
+impl Connection {
+  pub fn new(stream: TcpStream) -> io::Result<Self> {
+    stream.set_nonblocking(true)?;
+
+    stream.set_nodelay(true)?;
+
+    Ok(Self {
+      closed: false,
+      incoming: Vec::new(),
+      outgoing: Vec::new(),
+      stream,
+    })
+  }
+
+  /// Every complete message that has arrived.  An error means the
+  /// connection is closed or talking nonsense and should be dropped, but
+  /// whatever arrived before it closed is returned first.  A line longer
+  /// than MAX_LINE_BYTES closes the connection.
+  pub fn receive<T>(&mut self) -> io::Result<Vec<T>>
+  where
+    T: DeserializeOwned,
+  {
+    if self.closed {
+      return Err(ErrorKind::UnexpectedEof.into());
+    }
+
+    self.flush()?;
+
+    let mut buffer: [u8; 4096] = [0; 4096];
+
+    loop {
+      match self.stream.read(&mut buffer) {
+        Ok(0) => {
+          self.closed = true;
+
+          break;
+        },
+        Ok(count) => {
+          self.incoming.extend_from_slice(&buffer[..count]);
+
+          if self.unfinished_line() > MAX_LINE_BYTES {
+            self.closed = true;
+
+            return Err(io::Error::new(
+              ErrorKind::InvalidData,
+              "the peer sent a line that is too long",
+            ));
+          }
+        },
+        Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+        Err(error) if error.kind() == ErrorKind::Interrupted => {},
+        Err(error) => return Err(error),
+      }
+    }
+
+    let mut messages: Vec<T> = Vec::new();
+
+    while let Some(end) = self.incoming.iter().position(|byte| *byte == b'\n') {
+      let line: Vec<u8> = self.incoming.drain(..=end).collect();
+
+      messages.push(::serde_json::from_slice(&line)?);
+    }
+
+    if self.closed && messages.is_empty() {
+      return Err(ErrorKind::UnexpectedEof.into());
+    }
+
+    Ok(messages)
+  }
+
+  /// Queues the message and sends as much of the queue as the socket takes.
+  /// A queue past MAX_QUEUED_BYTES closes the connection.
+  pub fn send<T>(
+    &mut self,
+    message: &T,
+  ) -> io::Result<()>
+  where
+    T: Serialize,
+  {
+    ::serde_json::to_writer(&mut self.outgoing, message)?;
+
+    self.outgoing.push(b'\n');
+
+    self.flush()?;
+
+    if self.outgoing.len() > MAX_QUEUED_BYTES {
+      self.closed = true;
+
+      return Err(io::Error::new(
+        ErrorKind::TimedOut,
+        "the peer is not reading what is sent",
+      ));
+    }
+
+    Ok(())
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    while !self.outgoing.is_empty() {
+      match self.stream.write(&self.outgoing) {
+        Ok(0) => return Err(ErrorKind::WriteZero.into()),
+        Ok(count) => {
+          self.outgoing.drain(..count);
+        },
+        Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+        Err(error) if error.kind() == ErrorKind::Interrupted => {},
+        Err(error) => return Err(error),
+      }
+    }
+
+    Ok(())
+  }
+
+  /// The bytes received after the last complete line
+  fn unfinished_line(&self) -> usize {
+    let start: usize = self
+      .incoming
+      .iter()
+      .rposition(|byte| *byte == b'\n')
+      .map_or(0, |end| end + 1);
+
+    self.incoming.len() - start
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::super::client_message::ClientMessage;
+  use super::*;
+  use ::std::net::TcpListener;
+  use ::std::thread::{self, JoinHandle};
+  use ::std::time::Duration;
+
+  #[test]
+  fn test_long_line_closes() {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+
+    let mut client: TcpStream =
+      TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+
+    let mut server: Connection =
+      Connection::new(listener.accept().unwrap().0).unwrap();
+
+    let writer: JoinHandle<io::Result<()>> = thread::spawn(move || {
+      let chunk: Vec<u8> = vec![b' '; 4096];
+
+      (0..=MAX_LINE_BYTES / chunk.len())
+        .try_for_each(|_| client.write_all(&chunk))
+    });
+
+    let error: io::Error = loop {
+      match server.receive::<ClientMessage>() {
+        Ok(_) => thread::sleep(Duration::from_millis(1)),
+        Err(error) => break error,
+      }
+    };
+
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert!(server.receive::<ClientMessage>().is_err());
+
+    drop(server);
+
+    let _ = writer.join();
+  }
+
+  #[test]
+  fn test_messages_round_trip() {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+
+    let mut client: Connection = Connection::new(
+      TcpStream::connect(listener.local_addr().unwrap()).unwrap(),
+    )
+    .unwrap();
+
+    let mut server: Connection =
+      Connection::new(listener.accept().unwrap().0).unwrap();
+
+    client
+      .send(&ClientMessage::Roll {
+        dice: 3,
+      })
+      .unwrap();
+    client.send(&ClientMessage::Hold).unwrap();
+
+    let mut received: Vec<ClientMessage> = Vec::new();
+
+    while received.len() < 2 {
+      received.extend(server.receive::<ClientMessage>().unwrap());
+
+      thread::sleep(Duration::from_millis(1));
+    }
+
+    assert_eq!(
+      received,
+      [
+        ClientMessage::Roll {
+          dice: 3
+        },
+        ClientMessage::Hold,
+      ]
+    );
+
+    drop(client);
+
+    while server.receive::<ClientMessage>().is_ok() {
+      thread::sleep(Duration::from_millis(1));
+    }
+  }
+}
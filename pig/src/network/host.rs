@@ -0,0 +1,245 @@
+use super::client_message::ClientMessage;
+use super::connection::{Connection, PROTOCOL_VERSION};
+use super::host_message::HostMessage;
+use super::remote_seat::RemoteSeat;
+use ::my_lib::random::RandomNumberGenerator;
+use ::std::io::{self, ErrorKind};
+use ::std::net::{SocketAddr, TcpListener};
+use ::std::time::{Duration, Instant};
+
+/// How long a new connection has to say Hello before it is dropped
+pub const HELLO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Accepts players over TCP and seats them in the remote seats.  The host
+/// plays the game itself, so all it takes from a player is whether they
+/// roll or hold on their turn.
+#[derive(Debug)]
+pub struct Host {
+  joined: bool,
+  listener: TcpListener,
+  /// Connections that have not said Hello yet, with when they must have
+  pending: Vec<(Connection, Instant)>,
+  /// One per seat at the table, or None for a seat played on this machine
+  seats: Vec<Option<RemoteSeat>>,
+  /// Seeded from the operating system rather than the game's generator, so
+  /// that no player can work out another's session from the dice
+  sessions: RandomNumberGenerator,
+}
+
+impl Host {
+  pub fn bind(address: SocketAddr) -> io::Result<Self> {
+    let listener: TcpListener = TcpListener::bind(address)?;
+
+    listener.set_nonblocking(true)?;
+
+    Ok(Self {
+      joined: false,
+      listener,
+      pending: Vec::new(),
+      seats: Vec::new(),
+      sessions: RandomNumberGenerator::default(),
+    })
+  }
+
+  /// Sends the message to every connected player, dropping any connection
+  /// that fails
+  pub fn broadcast(
+    &mut self,
+    message: &HostMessage,
+  ) {
+    self.seats.iter_mut().flatten().for_each(|seat| {
+      if let Some(connection) = &mut seat.connection
+        && connection.send(message).is_err()
+      {
+        seat.connection = None;
+      }
+    });
+  }
+
+  pub fn local_addr(&self) -> io::Result<SocketAddr> {
+    self.listener.local_addr()
+  }
+
+  /// Accepts new connections and reads what the players sent
+  pub fn poll(&mut self) {
+    loop {
+      match self.listener.accept() {
+        Ok((stream, _)) => {
+          if let Ok(connection) = Connection::new(stream) {
+            self
+              .pending
+              .push((connection, Instant::now() + HELLO_TIMEOUT));
+          }
+        },
+        Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+        Err(_) => break,
+      }
+    }
+
+    let pending: Vec<(Connection, Instant)> = self.pending.drain(..).collect();
+
+    pending
+      .into_iter()
+      .for_each(|(connection, deadline)| self.greet(connection, deadline));
+
+    self.seats.iter_mut().flatten().for_each(|seat| {
+      let Some(connection) = &mut seat.connection else {
+        return;
+      };
+
+      match connection.receive::<ClientMessage>() {
+        Ok(messages) => seat.inbox.extend(
+          messages
+            .into_iter()
+            .filter(|message| !matches!(message, ClientMessage::Hello { .. })),
+        ),
+        Err(_) => seat.connection = None,
+      }
+    });
+  }
+
+  pub fn seat(
+    &self,
+    seat: usize,
+  ) -> Option<&RemoteSeat> {
+    self.seats.get(seat).and_then(Option::as_ref)
+  }
+
+  /// Marks which seats are played remotely.  Seats that stay remote keep
+  /// their players.
+  pub fn set_remote_seats(
+    &mut self,
+    remote: &[bool],
+  ) {
+    self.seats.resize_with(remote.len(), || None);
+
+    self
+      .seats
+      .iter_mut()
+      .zip(remote)
+      .for_each(|(seat, remote)| match (seat.is_some(), *remote) {
+        (false, true) => *seat = Some(RemoteSeat::default()),
+        (true, false) => *seat = None,
+        _ => {},
+      });
+  }
+
+  /// The next thing the player in the seat asked for
+  pub fn take(
+    &mut self,
+    seat: usize,
+  ) -> Option<ClientMessage> {
+    self.seats.get_mut(seat)?.as_mut()?.inbox.pop_front()
+  }
+
+  /// True once after a player joins or comes back, who will need to be sent
+  /// the game
+  pub fn take_joined(&mut self) -> bool {
+    ::std::mem::take(&mut self.joined)
+  }
+
+  /// Seats a new connection once it says Hello, or keeps it waiting until
+  /// its deadline.  Whatever it sent after the Hello goes into its seat's
+  /// inbox.
+  fn greet(
+    &mut self,
+    mut connection: Connection,
+    deadline: Instant,
+  ) {
+    let mut messages: Vec<ClientMessage> = match connection.receive() {
+      Ok(messages) => messages,
+      Err(_) => return,
+    };
+
+    let hello: Option<usize> = messages
+      .iter()
+      .position(|message| matches!(message, ClientMessage::Hello { .. }));
+
+    let Some(hello) = hello else {
+      if Instant::now() < deadline {
+        self.pending.push((connection, deadline));
+      }
+
+      return;
+    };
+
+    let following: Vec<ClientMessage> = messages
+      .split_off(hello + 1)
+      .into_iter()
+      .filter(|message| !matches!(message, ClientMessage::Hello { .. }))
+      .collect();
+
+    let Some(ClientMessage::Hello {
+      name,
+      session,
+      version,
+    }) = messages.pop()
+    else {
+      return;
+    };
+
+    if version != PROTOCOL_VERSION {
+      let _ = connection.send(&HostMessage::Rejected {
+        reason: format!(
+          "The host speaks version {PROTOCOL_VERSION}, not {version}"
+        ),
+      });
+
+      return;
+    }
+
+    let returning: Option<usize> = session.and_then(|session| {
+      self.seats.iter().position(|seat| {
+        seat
+          .as_ref()
+          .is_some_and(|seat| seat.session == Some(session))
+      })
+    });
+
+    let seat: Option<usize> = returning.or_else(|| {
+      self
+        .seats
+        .iter()
+        .position(|seat| seat.as_ref().is_some_and(|seat| !seat.is_claimed()))
+    });
+
+    let Some(seat) = seat else {
+      let _ = connection.send(&HostMessage::Rejected {
+        reason: "The table is full".into(),
+      });
+
+      return;
+    };
+
+    // Sessions are random so that no player can guess another's and take
+    // over their seat
+    let session: u64 = match returning {
+      Some(_) => session.unwrap_or_default(),
+      None => self.sessions.next(),
+    };
+
+    if connection
+      .send(&HostMessage::Welcome {
+        seat,
+        session,
+      })
+      .is_err()
+    {
+      return;
+    }
+
+    if let Some(remote_seat) = &mut self.seats[seat] {
+      remote_seat.connection = Some(connection);
+
+      remote_seat.inbox.clear();
+
+      remote_seat.inbox.extend(following);
+
+      remote_seat.name = Some(name);
+
+      remote_seat.session = Some(session);
+    }
+
+    self.joined = true;
+  }
+}
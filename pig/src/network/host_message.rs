@@ -0,0 +1,23 @@
+use super::super::model::pig_game::PigGame;
+use ::serde::{Deserialize, Serialize};
+
+/// What the host sends to the players who joined it
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum HostMessage {
+  /// The whole game after every change, with each seat's name and sRGBA
+  /// color
+  Game {
+    colors: Vec<[f32; 4]>,
+    game: Box<PigGame>,
+    names: Vec<String>,
+  },
+  /// The host would not seat the player, and closes the connection
+  Rejected {
+    reason: String,
+  },
+  /// The seat the player sits in, and the session to reclaim it with
+  Welcome {
+    seat: usize,
+    session: u64,
+  },
+}
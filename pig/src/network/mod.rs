@@ -0,0 +1,7 @@
+pub mod client;
+pub mod client_message;
+pub mod connecting;
+pub mod connection;
+pub mod host;
+pub mod host_message;
+pub mod remote_seat;
@@ -0,0 +1,24 @@
+use super::client_message::ClientMessage;
+use super::connection::Connection;
+use ::std::collections::VecDeque;
+
+/// A seat the host keeps for a player on another machine.  The seat stays
+/// claimed by its session when the connection drops, so that the same
+/// player can come back to it.
+#[derive(Debug, Default)]
+pub struct RemoteSeat {
+  pub connection: Option<Connection>,
+  pub inbox: VecDeque<ClientMessage>,
+  pub name: Option<String>,
+  pub session: Option<u64>,
+}
+
+impl RemoteSeat {
+  pub fn is_claimed(&self) -> bool {
+    self.session.is_some()
+  }
+
+  pub fn is_connected(&self) -> bool {
+    self.connection.is_some()
+  }
+}
@@ -0,0 +1,202 @@
+use super::bust_notice;
+use super::current_game::CurrentGame;
+use super::game_assets::GameAssets;
+use super::game_phase::GamePhase;
+use super::hand_die::{self, HandDie};
+use super::hog_dice::HogDice;
+use super::player_kind::PlayerKind;
+use super::resume::Resume;
+use super::roster::{MAX_PLAYERS, Roster};
+use super::roster_entry::RosterEntry;
+use super::tumble;
+use super::turn::{self, Turn};
+use super::turn_phase::TurnPhase;
+use ::bevy::prelude::*;
+use ::pig::model::pig_game::PigGame;
+use ::pig::model::turn_end::TurnEnd;
+use ::pig::model::turn_record::TurnRecord;
+use ::pig::network::client::Client;
+use ::pig::network::client_message::ClientMessage;
+use ::pig::network::host_message::HostMessage;
+
+/// This machine is playing a game hosted on another one while this exists.
+/// The host plays the game, and this one shows it and passes on what its
+/// human decides.
+#[derive(Deref, DerefMut, Resource)]
+pub struct NetworkClient(pub Client);
+
+/// Asks the host to roll or hold for the local player, then waits for the
+/// game to come back
+pub fn request(
+  mut client: ResMut<NetworkClient>,
+  hog_dice: Res<HogDice>,
+  turn: Res<State<Turn>>,
+  mut turn_state: ResMut<NextState<Turn>>,
+) {
+  let message: ClientMessage = match turn.phase {
+    TurnPhase::Banking => ClientMessage::Hold,
+    _ => ClientMessage::Roll {
+      dice: hog_dice.0,
+    },
+  };
+
+  client.send(&message);
+
+  turn_state.set(turn.with_phase(TurnPhase::Waiting));
+}
+
+/// Shows the game the host sent.  New dice tumble onto the table, and a
+/// hand that was lost stays there under the bust notice for a moment.
+#[expect(clippy::too_many_arguments)]
+pub fn sync(
+  assets: Option<Res<GameAssets>>,
+  mut client: ResMut<NetworkClient>,
+  mut commands: Commands,
+  game: Option<ResMut<CurrentGame>>,
+  hand_query: Query<Entity, With<HandDie>>,
+  phase: Res<State<GamePhase>>,
+  mut roster: ResMut<Roster>,
+  mut state: ResMut<NextState<GamePhase>>,
+  mut turn_state: ResMut<NextState<Turn>>,
+) {
+  // Only the latest game matters if several arrived at once
+  let Some(HostMessage::Game {
+    colors,
+    game: hosted,
+    names,
+  }) = client
+    .poll()
+    .into_iter()
+    .rfind(|message| matches!(message, HostMessage::Game { .. }))
+  else {
+    return;
+  };
+
+  let seat: Option<usize> = client.seat();
+
+  let players: usize = hosted.scores().len();
+
+  // A game that does not add up would only crash the client when shown
+  if !hosted.is_valid()
+    || players > MAX_PLAYERS
+    || names.len() != players
+    || colors.len() != players
+    || seat.is_some_and(|seat| seat >= players)
+  {
+    client.abandon("The host sent a game that does not add up".into());
+
+    return;
+  }
+
+  roster.players = names
+    .into_iter()
+    .zip(colors)
+    .enumerate()
+    .map(
+      |(
+        player,
+        (
+          name,
+          [
+            red,
+            green,
+            blue,
+            alpha,
+          ],
+        ),
+      )| RosterEntry {
+        color: Color::srgba(red, green, blue, alpha),
+        kind: if Some(player) == seat {
+          PlayerKind::Human
+        } else {
+          PlayerKind::Remote
+        },
+        name,
+      },
+    )
+    .collect();
+
+  let (Some(assets), Some(mut game), GamePhase::Playing) =
+    (assets, game, phase.get())
+  else {
+    if hosted.winner().is_none() {
+      commands.insert_resource(Resume(*hosted));
+
+      state.set(GamePhase::Playing);
+    }
+
+    return;
+  };
+
+  let previous: PigGame = ::std::mem::replace(&mut game.0, *hosted);
+
+  hand_die::clear(&hand_query, &mut commands);
+
+  let same_turn: bool = previous.history().len() == game.history().len();
+
+  let lost: Option<&TurnRecord> = game
+    .history()
+    .last()
+    .filter(|record| !same_turn && !matches!(record.end, TurnEnd::Banked(_)));
+
+  // A lost hand is shown as it was, with the roll that lost it tumbling
+  let (player, dice, settled): (usize, Vec<usize>, usize) = match lost {
+    Some(record) => {
+      let dice: Vec<usize> = record.rolls.concat();
+
+      let last_roll: usize = record.rolls.last().map_or(0, Vec::len);
+
+      (record.player, dice.clone(), dice.len() - last_roll)
+    },
+    None => (
+      game.current(),
+      game.hand().to_vec(),
+      if same_turn {
+        previous.hand().len()
+      } else {
+        0
+      },
+    ),
+  };
+
+  dice.iter().enumerate().for_each(|(position, die)| {
+    let seconds: f32 = if position < settled {
+      0.
+    } else {
+      tumble::SECONDS + (position - settled) as f32 * tumble::STAGGER_SECONDS
+    };
+
+    hand_die::spawn(
+      &mut commands,
+      &assets,
+      position,
+      *die,
+      roster.players[player].color,
+      seconds,
+    );
+  });
+
+  if let Some(record) = lost {
+    let message: &str = match record.end {
+      TurnEnd::LostScore => "Snake eyes! Score lost!",
+      _ => "Pigged out!",
+    };
+
+    bust_notice::spawn(&mut commands, message);
+  } else if game.winner().is_some() {
+    turn::end_turn(&mut commands, &game, &roster, &mut state, &mut turn_state);
+
+    return;
+  }
+
+  let phase: TurnPhase = if dice.len() > settled {
+    TurnPhase::Tumbling
+  } else {
+    TurnPhase::Deciding
+  };
+
+  turn_state.set(Turn {
+    phase,
+    player,
+  });
+}
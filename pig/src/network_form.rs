@@ -0,0 +1,144 @@
+use super::game_phase::GamePhase;
+use super::network_client::NetworkClient;
+use super::network_host::NetworkHost;
+use super::player_kind::PlayerKind;
+use super::roster::Roster;
+use ::bevy::prelude::*;
+use ::bevy_egui::{EguiContexts, egui};
+use ::pig::network::client::Client;
+use ::pig::network::connection::DEFAULT_PORT;
+use ::pig::network::host::Host;
+use ::std::net::SocketAddr;
+
+/// What the main menu's network window has typed into it
+pub struct NetworkForm {
+  pub address: String,
+  pub name: String,
+  pub port: String,
+}
+
+impl Default for NetworkForm {
+  fn default() -> Self {
+    Self {
+      address: format!("127.0.0.1:{DEFAULT_PORT}"),
+      name: "Guest".into(),
+      port: DEFAULT_PORT.to_string(),
+    }
+  }
+}
+
+/// Hosting and joining on the main menu, and how the connection is doing
+/// once a game is under way
+pub fn window(
+  client: Option<ResMut<NetworkClient>>,
+  mut commands: Commands,
+  mut egui_contexts: EguiContexts,
+  mut form: Local<NetworkForm>,
+  host: Option<Res<NetworkHost>>,
+  phase: Res<State<GamePhase>>,
+  roster: Res<Roster>,
+) -> Result {
+  let in_menu: bool = *phase.get() == GamePhase::MainMenu;
+
+  if !in_menu && client.is_none() && host.is_none() {
+    return Ok(());
+  }
+
+  let Ok(egui_context) = egui_contexts.ctx_mut() else {
+    return Ok(());
+  };
+
+  egui::Window::new("Network").show(egui_context, |ui: &mut egui::Ui| {
+    if let Some(host) = &host {
+      let port: u16 = host.local_addr().map_or(0, |address| address.port());
+
+      ui.label(format!("Hosting on port {port}"));
+
+      roster
+        .players
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.kind == PlayerKind::Remote)
+        .for_each(|(seat, entry)| {
+          let status: &str = match host.seat(seat) {
+            Some(remote) if remote.is_connected() => "connected",
+            Some(remote) if remote.is_claimed() => "reconnecting...",
+            _ => "waiting to join",
+          };
+
+          ui.label(format!("{}: {status}", entry.name));
+        });
+
+      if in_menu && ui.button("Stop Hosting").clicked() {
+        commands.remove_resource::<NetworkHost>();
+      }
+
+      return;
+    }
+
+    if let Some(client) = &client {
+      let status: String = match (client.rejected(), client.seat()) {
+        (Some(reason), _) => format!("Turned away: {reason}"),
+        _ if !client.is_connected() => "Reconnecting...".into(),
+        (None, Some(seat)) => format!("Joined in seat {}", seat + 1),
+        (None, None) => "Joining...".into(),
+      };
+
+      ui.label(format!("{}: {status}", client.address()));
+
+      if in_menu && ui.button("Leave").clicked() {
+        commands.remove_resource::<NetworkClient>();
+      }
+
+      return;
+    }
+
+    ui.horizontal(|ui: &mut egui::Ui| {
+      ui.label("Port");
+
+      ui.text_edit_singleline(&mut form.port);
+
+      if ui.button("Host").clicked() {
+        let address: Option<SocketAddr> =
+          form.port.parse::<u16>().ok().map(|port| {
+            SocketAddr::from((
+              [
+                0, 0, 0, 0,
+              ],
+              port,
+            ))
+          });
+
+        match address.map(Host::bind) {
+          Some(Ok(host)) => commands.insert_resource(NetworkHost(host)),
+          Some(Err(error)) => warn!("Could not host: {error}"),
+          None => warn!("Not a port: {}", form.port),
+        }
+      }
+    });
+
+    ui.horizontal(|ui: &mut egui::Ui| {
+      ui.label("Name");
+
+      ui.text_edit_singleline(&mut form.name);
+    });
+
+    ui.horizontal(|ui: &mut egui::Ui| {
+      ui.label("Host address");
+
+      ui.text_edit_singleline(&mut form.address);
+
+      if ui.button("Join").clicked() {
+        match form.address.parse::<SocketAddr>() {
+          Ok(address) => commands.insert_resource(NetworkClient(Client::new(
+            address,
+            form.name.clone(),
+          ))),
+          Err(error) => warn!("Not an address {}: {error}", form.address),
+        }
+      }
+    });
+  });
+
+  Ok(())
+}
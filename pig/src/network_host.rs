@@ -0,0 +1,98 @@
+use super::current_game::CurrentGame;
+use super::hog_dice::HogDice;
+use super::player_kind::PlayerKind;
+use super::roster::Roster;
+use super::turn::Turn;
+use super::turn_phase::TurnPhase;
+use ::bevy::prelude::*;
+use ::pig::model::variant::HOG_MAX_DICE;
+use ::pig::network::client_message::ClientMessage;
+use ::pig::network::host::Host;
+use ::pig::network::host_message::HostMessage;
+
+/// Other machines can join this one's game while this exists.  The remote
+/// seats in the roster are theirs.
+#[derive(Deref, DerefMut, Resource)]
+pub struct NetworkHost(pub Host);
+
+/// Sends the game to every joined player whenever it changes, and to anyone
+/// who just joined
+pub fn broadcast(
+  game: Res<CurrentGame>,
+  mut host: ResMut<NetworkHost>,
+  roster: Res<Roster>,
+) {
+  if !host.take_joined() && !game.is_changed() {
+    return;
+  }
+
+  host.broadcast(&HostMessage::Game {
+    colors: roster
+      .players
+      .iter()
+      .map(|entry| entry.color.to_srgba().to_f32_array())
+      .collect(),
+    game: Box::new(game.0.clone()),
+    names: roster
+      .players
+      .iter()
+      .map(|entry| entry.name.clone())
+      .collect(),
+  });
+}
+
+/// Seats players as they join and names their seats after them
+pub fn poll(
+  mut host: ResMut<NetworkHost>,
+  mut roster: ResMut<Roster>,
+) {
+  let remote: Vec<bool> = roster
+    .players
+    .iter()
+    .map(|entry| entry.kind == PlayerKind::Remote)
+    .collect();
+
+  host.set_remote_seats(&remote);
+
+  host.poll();
+
+  let renamed: Vec<(usize, String)> = (0..remote.len())
+    .filter_map(|seat| {
+      let name: &String = host.seat(seat)?.name.as_ref()?;
+
+      (*name != roster.players[seat].name).then(|| (seat, name.clone()))
+    })
+    .collect();
+
+  renamed.into_iter().for_each(|(seat, name)| {
+    roster.players[seat].name = name;
+  });
+}
+
+/// Plays whatever the remote player whose turn it is asked for.  Anything
+/// the others sent out of turn is ignored.
+pub fn remote(
+  game: Res<CurrentGame>,
+  mut hog_dice: ResMut<HogDice>,
+  mut host: ResMut<NetworkHost>,
+  turn: Res<State<Turn>>,
+  mut turn_state: ResMut<NextState<Turn>>,
+) {
+  (0..game.scores().len())
+    .filter(|seat| *seat != turn.player)
+    .for_each(|seat| while host.take(seat).is_some() {});
+
+  match host.take(turn.player) {
+    Some(ClientMessage::Hold) => {
+      turn_state.set(turn.with_phase(TurnPhase::Banking));
+    },
+    Some(ClientMessage::Roll {
+      dice,
+    }) if !game.must_hold() => {
+      hog_dice.0 = dice.clamp(1, HOG_MAX_DICE);
+
+      turn_state.set(turn.with_phase(TurnPhase::Rolling));
+    },
+    _ => {},
+  }
+}
@@ -0,0 +1,32 @@
+use ::serde::{Deserialize, Serialize};
+
+#[derive(
+  Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize,
+)]
+pub enum PlayerKind {
+  Cpu,
+  #[default]
+  Human,
+  /// Plays from another machine, either joined to this one or hosting the
+  /// game this one joined
+  Remote,
+}
+
+impl PlayerKind {
+  pub fn label(&self) -> &'static str {
+    match self {
+      Self::Cpu => "CPU",
+      Self::Human => "Human",
+      Self::Remote => "Remote",
+    }
+  }
+
+  /// The kind after this one, for a button that cycles through them
+  pub fn next(&self) -> Self {
+    match self {
+      Self::Cpu => Self::Remote,
+      Self::Human => Self::Cpu,
+      Self::Remote => Self::Human,
+    }
+  }
+}
@@ -0,0 +1,30 @@
+use ::serde::{Deserialize, Serialize};
+
+/// How one human has done over every finished game they played here
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PlayerStats {
+  pub games: usize,
+  /// The points banked over all of the player's turns, where a lost hand
+  /// counts as nothing
+  pub points: usize,
+  pub turns: usize,
+  pub wins: usize,
+}
+
+impl PlayerStats {
+  pub fn average_turn_total(&self) -> f64 {
+    if self.turns == 0 {
+      0.
+    } else {
+      self.points as f64 / self.turns as f64
+    }
+  }
+
+  pub fn win_rate(&self) -> f64 {
+    if self.games == 0 {
+      0.
+    } else {
+      self.wins as f64 / self.games as f64
+    }
+  }
+}
@@ -0,0 +1,6 @@
+use ::bevy::prelude::*;
+use ::pig::model::pig_game::PigGame;
+
+/// A saved game for the next game to pick up instead of starting afresh
+#[derive(Resource)]
+pub struct Resume(pub PigGame);
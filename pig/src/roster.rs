@@ -0,0 +1,124 @@
+use super::player_kind::PlayerKind;
+use super::roster_entry::RosterEntry;
+use ::bevy::prelude::*;
+use ::serde::{Deserialize, Serialize};
+
+pub const MAX_PLAYERS: usize = 6;
+
+pub const MIN_PLAYERS: usize = 2;
+
+/// A color for each seat, in seating order
+pub const PALETTE: [Color; MAX_PLAYERS] = [
+  Color::WHITE,
+  Color::srgb(0., 0., 1.),
+  Color::srgb(1., 0.3, 0.3),
+  Color::srgb(0.3, 1., 0.3),
+  Color::srgb(1., 0.9, 0.2),
+  Color::srgb(0.9, 0.4, 1.),
+];
+
+/// The players taking turns at one computer, in seating order
+#[derive(Clone, Debug, Deserialize, Resource, Serialize)]
+pub struct Roster {
+  pub players: Vec<RosterEntry>,
+}
+
+impl Roster {
+  /// Seats another human unless the table is full
+  pub fn add(&mut self) {
+    let seat: usize = self.players.len();
+
+    if seat >= MAX_PLAYERS {
+      return;
+    }
+
+    self.players.push(RosterEntry {
+      color: PALETTE[seat],
+      kind: PlayerKind::Human,
+      name: format!("Player {}", seat + 1),
+    });
+  }
+
+  pub fn is_cpu(
+    &self,
+    player: usize,
+  ) -> bool {
+    self.is_kind(player, PlayerKind::Cpu)
+  }
+
+  pub fn is_human(
+    &self,
+    player: usize,
+  ) -> bool {
+    self.is_kind(player, PlayerKind::Human)
+  }
+
+  pub fn is_remote(
+    &self,
+    player: usize,
+  ) -> bool {
+    self.is_kind(player, PlayerKind::Remote)
+  }
+
+  fn is_kind(
+    &self,
+    player: usize,
+    kind: PlayerKind,
+  ) -> bool {
+    self
+      .players
+      .get(player)
+      .is_some_and(|entry| entry.kind == kind)
+  }
+
+  /// Removes a player unless that would leave too few to play
+  pub fn remove(
+    &mut self,
+    player: usize,
+  ) {
+    if self.players.len() > MIN_PLAYERS && player < self.players.len() {
+      self.players.remove(player);
+    }
+  }
+}
+
+impl Default for Roster {
+  fn default() -> Self {
+    Self {
+      players: vec![
+        RosterEntry {
+          color: PALETTE[0],
+          kind: PlayerKind::Human,
+          name: "Player".to_string(),
+        },
+        RosterEntry {
+          color: PALETTE[1],
+          kind: PlayerKind::Cpu,
+          name: "CPU".to_string(),
+        },
+      ],
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_seat_limits() {
+    let mut roster: Roster = Roster::default();
+
+    roster.remove(0);
+
+    assert_eq!(roster.players.len(), MIN_PLAYERS);
+
+    (0..10).for_each(|_| roster.add());
+
+    assert_eq!(roster.players.len(), MAX_PLAYERS);
+    assert_eq!(roster.players[5].name, "Player 6");
+    assert!(roster.is_cpu(1));
+    assert!(!roster.is_cpu(2));
+    assert!(!roster.is_cpu(MAX_PLAYERS));
+  }
+}
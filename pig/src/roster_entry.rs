@@ -0,0 +1,11 @@
+use super::player_kind::PlayerKind;
+use ::bevy::prelude::*;
+use ::serde::{Deserialize, Serialize};
+
+/// One seat at the table.  The color tints the player's dice.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RosterEntry {
+  pub color: Color,
+  pub kind: PlayerKind,
+  pub name: String,
+}
@@ -0,0 +1,110 @@
+use super::roster::Roster;
+use ::bevy::prelude::*;
+use ::my_lib::persistence;
+use ::my_lib::random::RandomNumberGenerator;
+use ::pig::model::pig_game::PigGame;
+use ::pig::model::rules::Rules;
+use ::pig::strategies::strategy_kind::StrategyKind;
+use ::serde::{Deserialize, Serialize};
+use ::std::fs;
+use ::std::io::ErrorKind;
+use ::std::path::{Path, PathBuf};
+
+pub const FILE_VERSION: u32 = 2;
+
+/// A game in progress, saved as it is played so that quitting does not lose
+/// it.  The main menu offers to continue it while it is a resource.
+#[derive(Clone, Debug, Deserialize, Resource, Serialize)]
+pub struct SavedGame {
+  pub cpu_strategy: StrategyKind,
+  pub game: PigGame,
+  pub hog_dice: usize,
+  pub roster: Roster,
+  /// The game's generator as it was, so the dice carry on with the numbers
+  /// they would have rolled
+  pub rng: RandomNumberGenerator,
+  pub rules: Rules,
+  pub version: u32,
+}
+
+impl SavedGame {
+  /// The saved game in the platform data directory, if there is one
+  pub fn default_path() -> Option<PathBuf> {
+    persistence::data_path("pig-saved-game.json")
+  }
+
+  /// Forgets the saved game once it has been finished
+  pub fn delete(path: &Path) -> ::anyhow::Result<()> {
+    match fs::remove_file(path) {
+      Err(error) if error.kind() != ErrorKind::NotFound => Err(error.into()),
+      _ => Ok(()),
+    }
+  }
+
+  /// A missing or corrupt file, one from a newer version, or one that does
+  /// not match its own roster has nothing to continue
+  pub fn load(path: &Path) -> Option<Self> {
+    let saved_game: Self = persistence::load_json(path)?;
+
+    if saved_game.version > FILE_VERSION
+      || saved_game.game.scores().len() != saved_game.roster.players.len()
+      || saved_game.game.winner().is_some()
+    {
+      return None;
+    }
+
+    Some(saved_game)
+  }
+
+  pub fn save(
+    &self,
+    path: &Path,
+  ) -> ::anyhow::Result<()> {
+    persistence::save_json(self, path)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use ::std::env;
+
+  #[test]
+  fn test_round_trip() {
+    let path: PathBuf = env::temp_dir()
+      .join(format!("pig-saved-game-{}.json", ::std::process::id()));
+
+    let mut game: PigGame = PigGame::default();
+
+    game.roll(&[5]);
+    game.hold();
+    game.roll(&[3]);
+
+    let saved_game: SavedGame = SavedGame {
+      cpu_strategy: StrategyKind::Optimal,
+      game,
+      hog_dice: 4,
+      roster: Roster::default(),
+      rng: RandomNumberGenerator::seeded(42),
+      rules: Rules::default(),
+      version: FILE_VERSION,
+    };
+
+    saved_game.save(&path).unwrap();
+
+    let loaded: SavedGame = SavedGame::load(&path).unwrap();
+
+    assert_eq!(loaded.game, saved_game.game);
+    assert_eq!(loaded.game.hand(), &[3]);
+    assert_eq!(loaded.rng.seed(), 42);
+    assert_eq!(
+      loaded.rng.range(0..1_000_000),
+      saved_game.rng.range(0..1_000_000)
+    );
+
+    SavedGame::delete(&path).unwrap();
+
+    assert!(SavedGame::load(&path).is_none());
+    assert!(SavedGame::delete(&path).is_ok());
+  }
+}
@@ -0,0 +1,26 @@
+use super::current_game::CurrentGame;
+use super::roster::Roster;
+use ::bevy::prelude::*;
+
+/// The filled part of a player's bar on the scoreboard, which grows toward
+/// the target as they score
+#[derive(Component)]
+pub struct ScoreBar(pub usize);
+
+pub fn fill(
+  mut bar_query: Query<(&ScoreBar, &mut BackgroundColor, &mut Node)>,
+  game: Res<CurrentGame>,
+  roster: Res<Roster>,
+) {
+  bar_query
+    .iter_mut()
+    .for_each(|(bar, mut background, mut node)| {
+      let share: f32 = game.score(bar.0) as f32 / game.target() as f32;
+
+      node.width = Val::Percent(share.min(1.) * 100.);
+
+      if let Some(entry) = roster.players.get(bar.0) {
+        background.0 = entry.color;
+      }
+    });
+}
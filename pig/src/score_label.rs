@@ -0,0 +1,33 @@
+use super::current_game::CurrentGame;
+use super::roster::Roster;
+use ::bevy::prelude::*;
+
+/// A player's name and score on the scoreboard, marked while it is their
+/// turn
+#[derive(Component)]
+pub struct ScoreLabel(pub usize);
+
+pub fn update(
+  game: Res<CurrentGame>,
+  mut label_query: Query<(&ScoreLabel, &mut Text, &mut TextColor)>,
+  roster: Res<Roster>,
+) {
+  label_query
+    .iter_mut()
+    .for_each(|(label, mut text, mut color)| {
+      let Some(entry) = roster.players.get(label.0) else {
+        return;
+      };
+
+      let marker: &str = if game.winner().is_none() && game.current() == label.0
+      {
+        "> "
+      } else {
+        "  "
+      };
+
+      text.0 = format!("{marker}{}: {}", entry.name, game.score(label.0));
+
+      color.0 = entry.color;
+    });
+}
@@ -0,0 +1,11 @@
+use ::bevy::prelude::*;
+use ::pig::model::rules::Rules;
+use ::std::ops::RangeInclusive;
+
+/// The targets offered on the main menu.  The optimal strategy's table
+/// grows with the cube of the target, which keeps it from getting too big.
+pub const TARGETS: RangeInclusive<usize> = 20..=150;
+
+/// The rules chosen on the main menu for the next game
+#[derive(Default, Deref, DerefMut, Resource)]
+pub struct SelectedRules(pub Rules);
@@ -0,0 +1,5 @@
+use ::bevy::prelude::*;
+
+/// Whether the hints overlay is up, toggled during play
+#[derive(Default, PartialEq, Resource)]
+pub struct ShowHints(pub bool);
@@ -0,0 +1,47 @@
+use super::super::model::pig_game::PigGame;
+use super::strategy::Strategy;
+
+/// Rolls until the hand is worth at least this much or would win the game
+#[derive(Clone, Copy, Debug)]
+pub struct HoldAt(pub usize);
+
+impl Strategy for HoldAt {
+  fn should_roll(
+    &self,
+    game: &PigGame,
+  ) -> bool {
+    let turn_total: usize = game.turn_total();
+
+    turn_total < self.0
+      && game.score(game.current()) + turn_total < game.target()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_hold_at() {
+    let mut game: PigGame = PigGame::new(2, 30);
+
+    assert!(HoldAt(10).should_roll(&game));
+
+    game.roll(&[6]);
+    game.roll(&[4]);
+
+    assert!(!HoldAt(10).should_roll(&game));
+    assert!(HoldAt(20).should_roll(&game));
+
+    game.hold();
+    game.roll(&[6]);
+    game.roll(&[6]);
+    game.hold();
+    game.roll(&[6]);
+    game.roll(&[6]);
+    game.roll(&[4]);
+    game.roll(&[4]);
+
+    assert!(!HoldAt(25).should_roll(&game));
+  }
+}
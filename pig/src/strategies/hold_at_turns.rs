@@ -0,0 +1,46 @@
+use super::super::model::pig_game::PigGame;
+use super::strategy::Strategy;
+
+/// Aims to reach the target in this many turns by holding once the hand
+/// covers an even share of the points still needed
+#[derive(Clone, Copy, Debug)]
+pub struct HoldAtTurns(pub usize);
+
+impl Strategy for HoldAtTurns {
+  fn should_roll(
+    &self,
+    game: &PigGame,
+  ) -> bool {
+    let player: usize = game.current();
+
+    let needed: usize = game.target().saturating_sub(game.score(player));
+
+    let turns_left: usize =
+      self.0.saturating_sub(game.turns_taken(player)).max(1);
+
+    game.turn_total() < needed.div_ceil(turns_left)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_hold_at_turns() {
+    let mut game: PigGame = PigGame::new(2, 100);
+
+    game.roll(&[6]);
+    game.roll(&[6]);
+    game.roll(&[6]);
+    game.roll(&[6]);
+
+    // 100 points in 4 turns needs 25 a turn
+    assert!(HoldAtTurns(4).should_roll(&game));
+
+    game.roll(&[2]);
+
+    assert!(!HoldAtTurns(4).should_roll(&game));
+    assert!(HoldAtTurns(2).should_roll(&game));
+  }
+}
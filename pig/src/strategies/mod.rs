@@ -0,0 +1,6 @@
+pub mod hold_at;
+pub mod hold_at_turns;
+pub mod optimal;
+pub mod risk_averse;
+pub mod strategy;
+pub mod strategy_kind;
@@ -0,0 +1,223 @@
+use super::super::model::pig_game::PigGame;
+use super::super::model::variant::Variant;
+use super::hold_at::HoldAt;
+use super::strategy::Strategy;
+
+const TOLERANCE: f64 = 1e-9;
+
+/// The policy that maximizes the chance of winning a two-player game, found
+/// by value iteration over (my score, opponent score, turn total).  Against
+/// several opponents it plays against the best of them.  The table is only
+/// for standard Pig to its own target, so other games fall back to holding
+/// at 20.
+#[derive(Clone, Debug)]
+pub struct Optimal {
+  roll: Vec<bool>,
+  target: usize,
+  /// The chance of winning from each state, playing this policy against
+  /// itself
+  win: Vec<f32>,
+}
+
+impl Optimal {
+  pub fn new(target: usize) -> Self {
+    let mut win: Vec<f64> = vec![0.; target * target * target];
+
+    let mut roll: Vec<bool> = vec![false; win.len()];
+
+    let index = |score: usize, opponent: usize, turn_total: usize| {
+      (score * target + opponent) * target + turn_total
+    };
+
+    // Holding or rolling never lowers the sum of the banked scores, so each
+    // sum only depends on itself and higher sums, which are already solved
+    for sum in (0..=2 * (target - 1)).rev() {
+      let scores: Vec<usize> = (sum.saturating_sub(target - 1)..target)
+        .take_while(|score| *score <= sum)
+        .collect();
+
+      loop {
+        let mut change: f64 = 0.;
+
+        for &score in &scores {
+          let opponent: usize = sum - score;
+
+          // Higher turn totals first so each cell sees this pass's values
+          for turn_total in (0..target - score).rev() {
+            let hold: f64 = if score + turn_total >= target {
+              1.
+            } else {
+              1. - win[index(opponent, score + turn_total, 0)]
+            };
+
+            let pigged_out: f64 = 1. - win[index(opponent, score, 0)];
+
+            let rolled: f64 = (2..=6)
+              .map(|die| {
+                if score + turn_total + die >= target {
+                  1.
+                } else {
+                  win[index(score, opponent, turn_total + die)]
+                }
+              })
+              .sum::<f64>();
+
+            let chance_if_rolled: f64 = (pigged_out + rolled) / 6.;
+
+            let cell: usize = index(score, opponent, turn_total);
+
+            roll[cell] = chance_if_rolled > hold;
+
+            let chance: f64 = chance_if_rolled.max(hold);
+
+            change = change.max((chance - win[cell]).abs());
+
+            win[cell] = chance;
+          }
+        }
+
+        if change < TOLERANCE {
+          break;
+        }
+      }
+    }
+
+    Self {
+      roll,
+      target,
+      win: win.into_iter().map(|chance| chance as f32).collect(),
+    }
+  }
+
+  /// True if the table was solved for this game's rules
+  pub fn applies_to(
+    &self,
+    game: &PigGame,
+  ) -> bool {
+    game.variant() == Variant::Standard && game.target() == self.target
+  }
+
+  /// The current player's chance of winning if everyone plays optimally,
+  /// or None for a game the table was not solved for
+  pub fn win_chance(
+    &self,
+    game: &PigGame,
+  ) -> Option<f64> {
+    if !self.applies_to(game) {
+      return None;
+    }
+
+    let score: usize = game.score(game.current());
+
+    let turn_total: usize = game.turn_total();
+
+    if score + turn_total >= self.target {
+      return Some(1.);
+    }
+
+    let opponent: usize = game.best_opponent_score().min(self.target - 1);
+
+    Some(f64::from(self.win[self.index(score, opponent, turn_total)]))
+  }
+
+  fn index(
+    &self,
+    score: usize,
+    opponent: usize,
+    turn_total: usize,
+  ) -> usize {
+    (score * self.target + opponent) * self.target + turn_total
+  }
+}
+
+impl Strategy for Optimal {
+  fn should_roll(
+    &self,
+    game: &PigGame,
+  ) -> bool {
+    if !self.applies_to(game) {
+      return HoldAt(20).should_roll(game);
+    }
+
+    let score: usize = game.score(game.current());
+
+    let turn_total: usize = game.turn_total();
+
+    if score + turn_total >= self.target {
+      return false;
+    }
+
+    let opponent: usize = game.best_opponent_score().min(self.target - 1);
+
+    self.roll[self.index(score, opponent, turn_total)]
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_optimal_small_game() {
+    let optimal: Optimal = Optimal::new(10);
+
+    let mut game: PigGame = PigGame::new(2, 10);
+
+    // An empty hand is never worth holding
+    assert!(optimal.should_roll(&game));
+
+    game.roll(&[5]);
+    game.roll(&[5]);
+
+    assert!(!optimal.should_roll(&game));
+  }
+
+  #[test]
+  fn test_optimal_opening_turn() {
+    let optimal: Optimal = Optimal::new(100);
+
+    let mut game: PigGame = PigGame::new(2, 100);
+
+    // At 0 to 0 the optimal policy rolls until the hand reaches 21
+    [
+      6, 6, 6,
+    ]
+    .into_iter()
+    .for_each(|die| {
+      game.roll(&[die]);
+    });
+
+    game.roll(&[2]);
+
+    assert!(optimal.should_roll(&game));
+
+    game.roll(&[2]);
+
+    assert!(!optimal.should_roll(&game));
+  }
+
+  #[test]
+  fn test_optimal_falls_back_for_other_rules() {
+    let optimal: Optimal = Optimal::new(10);
+
+    let mut game: PigGame = PigGame::new(2, 50);
+
+    game.roll(&[5]);
+    game.roll(&[5]);
+
+    assert!(optimal.should_roll(&game));
+  }
+
+  #[test]
+  fn test_win_chance() {
+    let optimal: Optimal = Optimal::new(10);
+
+    let game: PigGame = PigGame::new(2, 10);
+
+    let chance: f64 = optimal.win_chance(&game).unwrap();
+
+    // Going first is an advantage
+    assert!(chance > 0.5 && chance < 1.);
+    assert_eq!(optimal.win_chance(&PigGame::new(2, 20)), None);
+  }
+}
@@ -0,0 +1,56 @@
+use super::super::model::pig_game::PigGame;
+use super::strategy::Strategy;
+
+/// Plays safe by holding early while ahead of every opponent, and takes
+/// bigger risks while behind
+#[derive(Clone, Copy, Debug)]
+pub struct RiskAverse {
+  pub ahead: usize,
+  pub behind: usize,
+}
+
+impl Strategy for RiskAverse {
+  fn should_roll(
+    &self,
+    game: &PigGame,
+  ) -> bool {
+    let score: usize = game.score(game.current());
+
+    let turn_total: usize = game.turn_total();
+
+    let hold_at: usize = if score >= game.best_opponent_score() {
+      self.ahead
+    } else {
+      self.behind
+    };
+
+    turn_total < hold_at && score + turn_total < game.target()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_risk_averse() {
+    let strategy: RiskAverse = RiskAverse {
+      ahead: 10,
+      behind: 25,
+    };
+
+    let mut game: PigGame = PigGame::new(2, 100);
+
+    game.roll(&[6]);
+    game.roll(&[6]);
+
+    assert!(!strategy.should_roll(&game));
+
+    game.hold();
+
+    game.roll(&[6]);
+    game.roll(&[6]);
+
+    assert!(strategy.should_roll(&game));
+  }
+}
@@ -0,0 +1,18 @@
+use super::super::model::pig_game::PigGame;
+use super::super::model::variant::HOG_DEFAULT_DICE;
+
+/// Decides whether the current player rolls again or holds
+pub trait Strategy: Send + Sync {
+  /// How many dice to roll, which only matters in Hog
+  fn dice(
+    &self,
+    game: &PigGame,
+  ) -> usize {
+    game.variant().dice().unwrap_or(HOG_DEFAULT_DICE)
+  }
+
+  fn should_roll(
+    &self,
+    game: &PigGame,
+  ) -> bool;
+}
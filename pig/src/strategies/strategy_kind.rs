@@ -0,0 +1,89 @@
+use super::hold_at::HoldAt;
+use super::hold_at_turns::HoldAtTurns;
+use super::optimal::Optimal;
+use super::risk_averse::RiskAverse;
+use super::strategy::Strategy;
+use ::serde::{Deserialize, Serialize};
+use ::std::str::FromStr;
+
+/// The CPU strategies offered on the main menu, from easiest to hardest
+#[derive(
+  Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize,
+)]
+pub enum StrategyKind {
+  HoldAtTurns,
+  #[default]
+  HoldAt,
+  RiskAverse,
+  Optimal,
+}
+
+impl StrategyKind {
+  pub fn all() -> [Self; 4] {
+    [
+      Self::HoldAtTurns,
+      Self::HoldAt,
+      Self::RiskAverse,
+      Self::Optimal,
+    ]
+  }
+
+  pub fn build(
+    &self,
+    target: usize,
+  ) -> Box<dyn Strategy> {
+    match self {
+      Self::HoldAt => Box::new(HoldAt(20)),
+      Self::HoldAtTurns => Box::new(HoldAtTurns(4)),
+      Self::Optimal => Box::new(Optimal::new(target)),
+      Self::RiskAverse => Box::new(RiskAverse {
+        ahead: 15,
+        behind: 25,
+      }),
+    }
+  }
+
+  /// A short name for the command line, such as "hold-at-20"
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::HoldAt => "hold-at-20",
+      Self::HoldAtTurns => "turns-4",
+      Self::Optimal => "optimal",
+      Self::RiskAverse => "risk-averse",
+    }
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      Self::HoldAt => "Hold at 20",
+      Self::HoldAtTurns => "Finish in 4 turns",
+      Self::Optimal => "Optimal",
+      Self::RiskAverse => "Risk averse",
+    }
+  }
+}
+
+impl FromStr for StrategyKind {
+  type Err = String;
+
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    Self::all()
+      .into_iter()
+      .find(|kind| kind.name() == name)
+      .ok_or_else(|| format!("Unknown strategy: {name}"))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_names_round_trip() {
+    StrategyKind::all().into_iter().for_each(|kind| {
+      assert_eq!(kind.name().parse::<StrategyKind>(), Ok(kind));
+    });
+
+    assert!("reckless".parse::<StrategyKind>().is_err());
+  }
+}
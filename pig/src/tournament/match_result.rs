@@ -0,0 +1,78 @@
+/// The outcome of many games between two strategies, A and B, which take
+/// turns going first
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MatchResult {
+  pub a_wins: usize,
+  pub first_player_wins: usize,
+  pub games: usize,
+  /// Turns taken across every game, counting both players
+  pub turns: usize,
+}
+
+impl MatchResult {
+  pub fn a_win_rate(&self) -> f64 {
+    self.rate(self.a_wins)
+  }
+
+  pub fn average_turns(&self) -> f64 {
+    self.rate(self.turns)
+  }
+
+  /// Half the width of the 95% confidence interval for a win rate
+  pub fn confidence(
+    &self,
+    rate: f64,
+  ) -> f64 {
+    if self.games == 0 {
+      return 0.;
+    }
+
+    1.96 * (rate * (1. - rate) / self.games as f64).sqrt()
+  }
+
+  pub fn first_player_win_rate(&self) -> f64 {
+    self.rate(self.first_player_wins)
+  }
+
+  pub fn merge(
+    &mut self,
+    other: &Self,
+  ) {
+    self.a_wins += other.a_wins;
+    self.first_player_wins += other.first_player_wins;
+    self.games += other.games;
+    self.turns += other.turns;
+  }
+
+  fn rate(
+    &self,
+    count: usize,
+  ) -> f64 {
+    if self.games == 0 {
+      return 0.;
+    }
+
+    count as f64 / self.games as f64
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_rates() {
+    let result: MatchResult = MatchResult {
+      a_wins: 60,
+      first_player_wins: 55,
+      games: 100,
+      turns: 2_500,
+    };
+
+    assert_eq!(result.a_win_rate(), 0.6);
+    assert_eq!(result.first_player_win_rate(), 0.55);
+    assert_eq!(result.average_turns(), 25.);
+    assert!((result.confidence(0.6) - 0.096).abs() < 0.001);
+    assert_eq!(MatchResult::default().a_win_rate(), 0.);
+  }
+}
@@ -0,0 +1,2 @@
+pub mod match_result;
+pub mod simulator;
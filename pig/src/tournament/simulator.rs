@@ -0,0 +1,190 @@
+use super::super::model::pig_game::PigGame;
+use super::super::model::rules::Rules;
+use super::super::strategies::strategy::Strategy;
+use super::match_result::MatchResult;
+use ::my_lib::random::RandomNumberGenerator;
+use ::std::thread;
+
+/// Spreads the games' seeds out across the seed space, as SplitMix64 does,
+/// so that neighbouring games do not start from neighbouring seeds
+const SEED_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Plays one game with a strategy per seat and returns the winning seat and
+/// the number of turns taken
+pub fn play_game(
+  strategies: &[&dyn Strategy],
+  rules: Rules,
+  rng: &RandomNumberGenerator,
+) -> (usize, usize) {
+  let mut game: PigGame = rules.new_game(strategies.len());
+
+  loop {
+    let strategy: &dyn Strategy = strategies[game.current()];
+
+    if !game.must_hold() && strategy.should_roll(&game) {
+      let dice: Vec<usize> = (0..strategy.dice(&game))
+        .map(|_| rng.range(1..=6))
+        .collect();
+
+      game.roll(&dice);
+    } else {
+      game.hold();
+    }
+
+    if let Some(winner) = game.winner() {
+      let turns: usize = (0..strategies.len())
+        .map(|player| game.turns_taken(player))
+        .sum();
+
+      return (winner, turns);
+    }
+  }
+}
+
+/// Plays `games` games between A and B on `threads` threads, alternating
+/// which of them goes first.  Each game is seeded from the seed and its
+/// place in the match, so the same seed gives the same result however many
+/// threads play it.
+pub fn play_match(
+  a: &dyn Strategy,
+  b: &dyn Strategy,
+  rules: Rules,
+  games: usize,
+  seed: u64,
+  threads: usize,
+) -> MatchResult {
+  let threads: usize = threads.clamp(1, games.max(1));
+
+  thread::scope(|scope| {
+    let handles: Vec<_> = (0..threads)
+      .map(|thread_index| {
+        let first_game: usize = games * thread_index / threads;
+
+        let last_game: usize = games * (thread_index + 1) / threads;
+
+        scope.spawn(move || {
+          let mut result: MatchResult = MatchResult::default();
+
+          (first_game..last_game).for_each(|game_index| {
+            let a_first: bool = game_index % 2 == 0;
+
+            let seats: [&dyn Strategy; 2] = if a_first {
+              [
+                a, b,
+              ]
+            } else {
+              [
+                b, a,
+              ]
+            };
+
+            let rng: RandomNumberGenerator = RandomNumberGenerator::seeded(
+              seed.wrapping_add((game_index as u64).wrapping_mul(SEED_GAMMA)),
+            );
+
+            let (winner, turns) = play_game(&seats, rules, &rng);
+
+            result.games += 1;
+
+            result.turns += turns;
+
+            if winner == 0 {
+              result.first_player_wins += 1;
+            }
+
+            if (winner == 0) == a_first {
+              result.a_wins += 1;
+            }
+          });
+
+          result
+        })
+      })
+      .collect();
+
+    handles
+      .into_iter()
+      .fold(MatchResult::default(), |mut total, handle| {
+        total.merge(&handle.join().unwrap());
+
+        total
+      })
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use super::super::super::model::variant::Variant;
+  use super::super::super::strategies::hold_at::HoldAt;
+  use super::*;
+
+  #[test]
+  fn test_play_game_is_repeatable() {
+    let strategies: [&dyn Strategy; 2] = [
+      &HoldAt(20),
+      &HoldAt(15),
+    ];
+
+    let first: (usize, usize) = play_game(
+      &strategies,
+      Rules::default(),
+      &RandomNumberGenerator::seeded(3),
+    );
+
+    let second: (usize, usize) = play_game(
+      &strategies,
+      Rules::default(),
+      &RandomNumberGenerator::seeded(3),
+    );
+
+    assert_eq!(first, second);
+    assert!(first.1 >= 5);
+  }
+
+  #[test]
+  fn test_play_match() {
+    let result: MatchResult =
+      play_match(&HoldAt(20), &HoldAt(2), Rules::default(), 2_000, 1, 4);
+
+    assert_eq!(result.games, 2_000);
+    assert!(result.a_win_rate() > 0.9);
+    assert_eq!(
+      result,
+      play_match(&HoldAt(20), &HoldAt(2), Rules::default(), 2_000, 1, 4)
+    );
+  }
+
+  #[test]
+  fn test_play_match_ignores_threads() {
+    let play = |threads: usize| {
+      play_match(&HoldAt(20), &HoldAt(25), Rules::default(), 501, 9, threads)
+    };
+
+    assert_eq!(play(1), play(4));
+  }
+
+  #[test]
+  fn test_play_variants() {
+    let rng: RandomNumberGenerator = RandomNumberGenerator::seeded(5);
+
+    let strategies: [&dyn Strategy; 2] = [
+      &HoldAt(20),
+      &HoldAt(25),
+    ];
+
+    Variant::all().into_iter().for_each(|variant| {
+      let rules: Rules = Rules {
+        finish_round: variant == Variant::TwoDice,
+        target: 50,
+        variant,
+      };
+
+      (0..20).for_each(|_| {
+        let (winner, turns) = play_game(&strategies, rules, &rng);
+
+        assert!(winner < 2);
+        assert!(turns >= 2);
+      });
+    });
+  }
+}
@@ -0,0 +1,57 @@
+use ::bevy::prelude::*;
+
+/// How often a tumbling die shows a new face
+const FLIP_SECONDS: f32 = 0.06;
+
+/// How long a die tumbles, with each die in a roll settling a little after
+/// the one before it
+pub const SECONDS: f32 = 0.4;
+
+pub const STAGGER_SECONDS: f32 = 0.12;
+
+/// A die that flips through random faces until its timer runs out, then
+/// settles on the face that was rolled
+#[derive(Component)]
+pub struct Tumble {
+  pub face: usize,
+  pub flip: Timer,
+  pub timer: Timer,
+}
+
+impl Tumble {
+  pub fn new(
+    face: usize,
+    seconds: f32,
+  ) -> Self {
+    Self {
+      face,
+      flip: Timer::from_seconds(FLIP_SECONDS, TimerMode::Repeating),
+      timer: Timer::from_seconds(seconds, TimerMode::Once),
+    }
+  }
+}
+
+pub fn animate(
+  mut commands: Commands,
+  time: Res<Time>,
+  mut tumble_query: Query<(Entity, &mut Sprite, &mut Tumble)>,
+) {
+  tumble_query
+    .iter_mut()
+    .for_each(|(entity, mut sprite, mut tumble)| {
+      let Some(atlas) = &mut sprite.texture_atlas else {
+        return;
+      };
+
+      if tumble.timer.tick(time.delta()).is_finished() {
+        atlas.index = tumble.face - 1;
+
+        commands.entity(entity).remove::<Tumble>();
+      } else if tumble.flip.tick(time.delta()).just_finished() {
+        // The faces shown on the way are only for show, so they step
+        // through the atlas rather than take numbers from the game's
+        // generator.  The step never wraps around to the same face.
+        atlas.index = (atlas.index + 1 + tumble.face % 5) % 6;
+      }
+    });
+}
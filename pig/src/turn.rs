@@ -0,0 +1,154 @@
+use super::current_game::CurrentGame;
+use super::game_phase::GamePhase;
+use super::network_client::NetworkClient;
+use super::network_host::NetworkHost;
+use super::player_kind::PlayerKind;
+use super::roster::Roster;
+use super::turn_phase::TurnPhase;
+use ::bevy::prelude::*;
+use ::my_lib::game_result::GameResult;
+
+/// Whose turn it is and how far through it they are.  Only exists while a
+/// game is being played, and starts over with the first seat each game.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, SubStates)]
+#[source(GamePhase = GamePhase::Playing)]
+pub struct Turn {
+  pub phase: TurnPhase,
+  pub player: usize,
+}
+
+impl Turn {
+  /// The start of the given player's turn
+  pub fn start(player: usize) -> Self {
+    Self {
+      phase: TurnPhase::Deciding,
+      player,
+    }
+  }
+
+  pub fn with_phase(
+    &self,
+    phase: TurnPhase,
+  ) -> Self {
+    Self {
+      phase,
+      player: self.player,
+    }
+  }
+}
+
+/// Starts the next turn, or records the result if the turn ended the game,
+/// which can happen on a pig out when the round is being finished
+pub fn end_turn(
+  commands: &mut Commands,
+  game: &CurrentGame,
+  roster: &Roster,
+  state: &mut NextState<GamePhase>,
+  turn_state: &mut NextState<Turn>,
+) {
+  let Some(winner) = game.winner() else {
+    turn_state.set(Turn::start(game.current()));
+
+    return;
+  };
+
+  // The high score table is for the humans, so it gets the first human's
+  // score whoever won
+  let score: usize = roster
+    .players
+    .iter()
+    .position(|entry| entry.kind == PlayerKind::Human)
+    .map_or(0, |player| game.score(player));
+
+  commands.insert_resource(GameResult {
+    score,
+    time: None,
+    winner: Some(roster.players[winner].name.clone()),
+  });
+
+  state.set(GamePhase::GameOver);
+}
+
+/// Run condition that is true during the given phase of anyone's turn
+pub fn in_turn_phase(
+  phase: TurnPhase
+) -> impl FnMut(Option<Res<State<Turn>>>) -> bool + Clone {
+  move |turn: Option<Res<State<Turn>>>| {
+    turn.is_some_and(|turn| turn.get().phase == phase)
+  }
+}
+
+/// Run condition that is true during a CPU player's turn.  The CPU also
+/// plays the remote seats when this machine is neither hosting nor joined,
+/// since nobody else could ever play them.
+pub fn is_cpu_turn(
+  client: Option<Res<NetworkClient>>,
+  host: Option<Res<NetworkHost>>,
+  roster: Res<Roster>,
+  turn: Option<Res<State<Turn>>>,
+) -> bool {
+  let offline: bool = client.is_none() && host.is_none();
+
+  turn.is_some_and(|turn| {
+    let player: usize = turn.get().player;
+
+    roster.is_cpu(player) || (offline && roster.is_remote(player))
+  })
+}
+
+/// Run condition that is true once the Turn belongs to the player the game
+/// says is up.  A resumed game sets its Turn on entering Playing, so for a
+/// frame the Turn is still the first seat's while the game is on another.
+pub fn is_game_turn(
+  game: Res<CurrentGame>,
+  turn: Option<Res<State<Turn>>>,
+) -> bool {
+  turn.is_some_and(|turn| turn.get().player == game.current())
+}
+
+/// Run condition that is true during the turn of a human at this machine
+pub fn is_human_turn(
+  roster: Res<Roster>,
+  turn: Option<Res<State<Turn>>>,
+) -> bool {
+  turn.is_some_and(|turn| roster.is_human(turn.get().player))
+}
+
+/// Run condition that is true during the turn of a player on another
+/// machine
+pub fn is_remote_turn(
+  roster: Res<Roster>,
+  turn: Option<Res<State<Turn>>>,
+) -> bool {
+  turn.is_some_and(|turn| roster.is_remote(turn.get().player))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use ::bevy::ecs::system::RunSystemOnce;
+  use ::pig::model::pig_game::PigGame;
+
+  #[test]
+  fn test_resumed_game_waits_for_its_turn() {
+    let mut game: PigGame = PigGame::new(3, 100);
+
+    game.roll(&[4]);
+    game.hold();
+    game.roll(&[3]);
+
+    assert_eq!(game.current(), 1);
+
+    let mut world: World = World::new();
+
+    world.insert_resource(CurrentGame(game));
+
+    world.insert_resource(State::new(Turn::default()));
+
+    assert!(!world.run_system_once(is_game_turn).unwrap());
+
+    world.insert_resource(State::new(Turn::start(1)));
+
+    assert!(world.run_system_once(is_game_turn).unwrap());
+  }
+}
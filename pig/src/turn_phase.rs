@@ -0,0 +1,18 @@
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TurnPhase {
+  /// The hand is added to the player's score and play passes on
+  Banking,
+  /// The roll lost the hand, which stays on the table for a moment so the
+  /// player can see why before the turn passes on
+  Busted,
+  /// The player chooses to roll again or bank the hand
+  #[default]
+  Deciding,
+  /// The dice are rolled and added to the hand, or lose it
+  Rolling,
+  /// The rolled dice tumble before settling on their faces
+  Tumbling,
+  /// A joined player's roll or hold is with the host, which sends back the
+  /// game once it has played it
+  Waiting,
+}
@@ -0,0 +1,35 @@
+use super::current_game::CurrentGame;
+use super::roster::Roster;
+use ::bevy::prelude::*;
+
+/// The rules, whose turn it is and what their hand is worth, above the
+/// score bars
+#[derive(Component)]
+pub struct TurnReadout;
+
+pub fn update(
+  game: Res<CurrentGame>,
+  mut readout_query: Query<&mut Text, With<TurnReadout>>,
+  roster: Res<Roster>,
+) {
+  let Ok(mut text) = readout_query.single_mut() else {
+    return;
+  };
+
+  let mut lines: Vec<String> =
+    vec![format!("{} to {}", game.variant().label(), game.target())];
+
+  if game.is_tiebreaker() {
+    lines.push("Tiebreaker!".into());
+  } else if game.is_final_round() {
+    lines.push("Final round!".into());
+  }
+
+  if let Some(entry) = roster.players.get(game.current()) {
+    lines.push(format!("{}'s turn", entry.name));
+  }
+
+  lines.push(format!("Hand: {}", game.turn_total()));
+
+  text.0 = lines.join("\n");
+}